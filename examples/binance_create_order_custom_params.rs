@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 use binance::api::*;
 use binance::account::*;
+use binance::model::to_number;
 
 fn main() {
     create_order_with_params()
@@ -10,6 +11,7 @@ fn create_order_with_params() {
     let api_key: &str = "YOUR_API_KEY";
     let secret_key: &str = "YOUR_SECRET_KEY";
 
+    #[allow(unused_mut)] // needed once verbose logging is enabled below
     let mut account: Account = Binance::new(Some(api_key.into()), Some(secret_key.into()));
 
     // account.set_verbose(true); // Uncomment to enable verbose logging
@@ -23,8 +25,8 @@ fn create_order_with_params() {
 
     let order = account.custom_order_with_params(
         "BNBUSDT",
-        0.1,
-        300.0,
+        to_number(0.1),
+        to_number(300.0),
         None,
         OrderSide::Buy,
         OrderType::Limit,
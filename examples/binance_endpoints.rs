@@ -4,7 +4,7 @@ use binance::config::*;
 use binance::general::*;
 use binance::account::*;
 use binance::market::*;
-use binance::model::KlineSummary;
+use binance::model::{to_number, KlineSummary};
 use binance::errors::ErrorKind as BinanceLibErrorKind;
 
 fn main() {
@@ -81,7 +81,7 @@ fn account() {
         Err(e) => println!("Error: {}", e),
     }
 
-    match account.limit_buy("WTCETH", 10, 0.014000) {
+    match account.limit_buy("WTCETH", 10, to_number(0.014)) {
         Ok(answer) => println!("{:?}", answer),
         Err(e) => println!("Error: {}", e),
     }
@@ -96,7 +96,7 @@ fn account() {
         Err(e) => println!("Error: {}", e),
     }
 
-    match account.limit_sell("WTCETH", 10, 0.035000) {
+    match account.limit_sell("WTCETH", 10, to_number(0.035)) {
         Ok(answer) => println!("{:?}", answer),
         Err(e) => println!("Error: {}", e),
     }
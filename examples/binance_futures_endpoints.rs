@@ -1,13 +1,20 @@
 use binance::api::*;
+use binance::futures::account::ContractType;
+use binance::futures::coinm::general::CoinMGeneral;
+use binance::futures::coinm::market::CoinMMarket;
 use binance::futures::general::*;
 use binance::futures::market::*;
+use binance::futures::registry::SymbolRegistry;
 use binance::futures::model::*;
+use binance::model::to_number;
 use binance::errors::ErrorKind as BinanceLibErrorKind;
+use std::time::Duration;
 
 fn main() {
     general();
     //account();
     market_data();
+    coinm_market_data();
 }
 
 fn general() {
@@ -41,6 +48,26 @@ fn general() {
         Ok(answer) => println!("Symbol information: {:?}", answer),
         Err(e) => println!("Error: {}", e),
     }
+
+    // exchange information cached for an hour
+    let registry = SymbolRegistry::new(general, Duration::from_secs(3600));
+
+    match registry.symbols_by_pair("BTCUSDT") {
+        Ok(answer) => {
+            for symbol in answer {
+                println!(
+                    "{} {} {}",
+                    symbol.symbol, symbol.contract_type, symbol.delivery_date
+                );
+            }
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match registry.symbols_by_margin_asset("USDC") {
+        Ok(answer) => println!("USDC margined symbols: {}", answer.len()),
+        Err(e) => println!("Error: {}", e),
+    }
 }
 
 fn market_data() {
@@ -66,6 +93,20 @@ fn market_data() {
         Err(e) => println!("Error: {}", e),
     }
 
+    match market.get_continuous_klines("BTCUSDT", ContractType::Perpetual, "5m", 10, None, None) {
+        Ok(KlineSummaries::AllKlineSummaries(answer)) => {
+            println!("First perpetual kline: {:?}", answer[0])
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match market.get_mark_price_klines("BTCUSDT", "5m", 10, None, None) {
+        Ok(KlineSummaries::AllKlineSummaries(answer)) => {
+            println!("First mark price kline: {:?}", answer[0])
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+
     match market.get_24h_price_stats("btcusdt") {
         Ok(answer) => println!("24hr price stats: {:?}", answer),
         Err(e) => println!("Error: {}", e),
@@ -91,6 +132,24 @@ fn market_data() {
         Err(e) => println!("Error: {}", e),
     }
 
+    match market.get_mark_price("btcusdt") {
+        Ok(answer) => println!(
+            "Mark price: {}, funding rate: {}",
+            answer.mark_price, answer.last_funding_rate
+        ),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match market.get_funding_rate_history("btcusdt", None, None, 10) {
+        Ok(answer) => println!("Last funding rates: {:?}", answer),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match market.get_funding_info() {
+        Ok(answer) => println!("Adjusted funding: {:?}", answer),
+        Err(e) => println!("Error: {}", e),
+    }
+
     match market.get_all_liquidation_orders() {
         Ok(LiquidationOrders::AllLiquidationOrders(answer)) => {
             println!("First liquidation order: {:?}", answer[0]);
@@ -102,4 +161,58 @@ fn market_data() {
         Ok(answer) => println!("Open interest: {:?}", answer),
         Err(e) => println!("Error: {}", e),
     }
+
+    match market.global_long_short_account_ratio("BTCUSDT", "1h", 24, None, None) {
+        Ok(answer) => println!("Long/short ratios: {:?}", answer),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match market.taker_buy_sell_volume("BTCUSDT", "1h", 24, None, None) {
+        Ok(answer) => println!("Taker buy/sell volumes: {:?}", answer),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match market.basis("BTCUSDT", ContractType::Perpetual, "1h", 24, None, None) {
+        Ok(answer) => println!("Basis: {:?}", answer),
+        Err(e) => println!("Error: {}", e),
+    }
+}
+
+fn coinm_market_data() {
+    let general: CoinMGeneral = Binance::new(None, None);
+
+    match general.get_symbol_info("BTCUSD_PERP") {
+        Ok(answer) => {
+            println!(
+                "{} contract size: {} USD, delivery: {}",
+                answer.symbol, answer.contract_size, answer.delivery_date
+            );
+            // 0.01 BTC worth of contracts at 60000 USD/BTC
+            println!(
+                "Contracts: {:?}",
+                answer.contracts(to_number(0.01), to_number(60000.0))
+            );
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+
+    let market: CoinMMarket = Binance::new(None, None);
+
+    match market.get_depth("BTCUSD_PERP", 5) {
+        Ok(answer) => println!("Best bid: {:?}", answer.bids[0]),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match market.get_pair_mark_prices("BTCUSD") {
+        Ok(answer) => println!("Mark prices: {:?}", answer),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    match market.get_continuous_klines("BTCUSD", ContractType::CurrentQuarter, "1h", 10, None, None)
+    {
+        Ok(KlineSummaries::AllKlineSummaries(answer)) => {
+            println!("First quarterly kline: {:?}", answer[0])
+        }
+        Err(e) => println!("Error: {}", e),
+    }
 }
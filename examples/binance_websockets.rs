@@ -172,8 +172,8 @@ fn last_price_for_one_symbol() {
 
     let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
         if let WebsocketEvent::DayTicker(ticker_event) = event {
-            btcusdt = ticker_event.average_price.parse().unwrap();
-            let btcusdt_close: f32 = ticker_event.current_close.parse().unwrap();
+            btcusdt = ticker_event.average_price.to_string().parse().unwrap();
+            let btcusdt_close: f32 = ticker_event.current_close.to_string().parse().unwrap();
             println!("{} - {}", btcusdt, btcusdt_close);
 
             if btcusdt_close as i32 == 7000 {
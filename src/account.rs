@@ -1,16 +1,26 @@
 use error_chain::bail;
 
 use crate::util::{build_signed_request, is_start_time_valid, uuid_spot};
+use crate::validator::OrderValidator;
 use crate::model::{
-    AccountInformation, Balance, Empty, Order, OrderCanceled, TradeHistory, Transaction,
+    AccountCommission, AccountInformation, Allocation, Balance, ExchangeInformation, Number, Empty,
+    Order, OrderAmendment, OrderCancelReplace, OrderCanceled, OrderList, PreventedMatch,
+    SorTransaction, TradeHistory, Transaction, UnfilledOrderCount,
 };
 use crate::client::Client;
-use crate::errors::Result;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+use crate::errors::{Error, ErrorKind, Result};
 use std::collections::BTreeMap;
 use std::fmt::Display;
 use crate::api::API;
 use crate::api::Spot;
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncAccount")
+)]
 #[derive(Clone)]
 pub struct Account {
     pub client: Client,
@@ -19,9 +29,9 @@ pub struct Account {
 
 struct OrderRequest {
     pub symbol: String,
-    pub qty: f64,
-    pub price: f64,
-    pub stop_price: Option<f64>,
+    pub qty: Number,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
     pub order_side: OrderSide,
     pub order_type: OrderType,
     pub time_in_force: TimeInForce,
@@ -30,11 +40,9 @@ struct OrderRequest {
 
 struct OrderQuoteQuantityRequest {
     pub symbol: String,
-    pub quote_order_qty: f64,
-    pub price: f64,
+    pub quote_order_qty: Number,
     pub order_side: OrderSide,
     pub order_type: OrderType,
-    pub time_in_force: TimeInForce,
     pub new_client_order_id: Option<String>,
 }
 
@@ -42,6 +50,10 @@ pub enum OrderType {
     Limit,
     Market,
     StopLossLimit,
+    StopLoss,
+    TakeProfit,
+    TakeProfitLimit,
+    LimitMaker,
 }
 
 impl OrderType {
@@ -50,6 +62,10 @@ impl OrderType {
             1 => Some(OrderType::Limit),
             2 => Some(OrderType::Market),
             3 => Some(OrderType::StopLossLimit),
+            4 => Some(OrderType::StopLoss),
+            5 => Some(OrderType::TakeProfit),
+            6 => Some(OrderType::TakeProfitLimit),
+            7 => Some(OrderType::LimitMaker),
             _ => None,
         }
     }
@@ -61,6 +77,10 @@ impl Display for OrderType {
             Self::Limit => write!(f, "LIMIT"),
             Self::Market => write!(f, "MARKET"),
             Self::StopLossLimit => write!(f, "STOP_LOSS_LIMIT"),
+            Self::StopLoss => write!(f, "STOP_LOSS"),
+            Self::TakeProfit => write!(f, "TAKE_PROFIT"),
+            Self::TakeProfitLimit => write!(f, "TAKE_PROFIT_LIMIT"),
+            Self::LimitMaker => write!(f, "LIMIT_MAKER"),
         }
     }
 }
@@ -118,20 +138,636 @@ impl Display for TimeInForce {
     }
 }
 
+pub enum SelfTradePreventionMode {
+    None,
+    ExpireTaker,
+    ExpireMaker,
+    ExpireBoth,
+    Decrement,
+}
+
+impl Display for SelfTradePreventionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "NONE"),
+            Self::ExpireTaker => write!(f, "EXPIRE_TAKER"),
+            Self::ExpireMaker => write!(f, "EXPIRE_MAKER"),
+            Self::ExpireBoth => write!(f, "EXPIRE_BOTH"),
+            Self::Decrement => write!(f, "DECREMENT"),
+        }
+    }
+}
+
+/// How much of the order the response describes.
+pub enum NewOrderResponseType {
+    /// Ids and transaction time only
+    Ack,
+    /// The order as placed, without its fills
+    Result,
+    /// The order and its fills
+    Full,
+}
+
+impl Display for NewOrderResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ack => write!(f, "ACK"),
+            Self::Result => write!(f, "RESULT"),
+            Self::Full => write!(f, "FULL"),
+        }
+    }
+}
+
+/// A spot order of any type, for `Account::place_order` and `Account::test_place_order`.
+///
+/// `build` checks the parameters the order type requires and returns the
+/// `ValidatedSpotOrder` those methods take. Prices and quantities are sent as given:
+/// call `apply_filters` before `build` to round them to the symbol's tick and step size.
+pub struct SpotOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<Number>,
+    /// `MARKET` orders only, instead of `quantity`
+    pub quote_order_qty: Option<Number>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
+    /// Trailing stop, in basis points.
+    pub trailing_delta: Option<u64>,
+    /// `LIMIT`, `LIMIT_MAKER`, `STOP_LOSS_LIMIT` or `TAKE_PROFIT_LIMIT` with `GTC` only
+    pub iceberg_qty: Option<Number>,
+    /// Automatically generated if not sent.
+    pub new_client_order_id: Option<String>,
+    pub strategy_id: Option<u64>,
+    /// At least 1000000
+    pub strategy_type: Option<u64>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    pub new_order_resp_type: Option<NewOrderResponseType>,
+}
+
+impl SpotOrderRequest {
+    pub fn new<S: Into<String>>(symbol: S, side: OrderSide, order_type: OrderType) -> Self {
+        SpotOrderRequest {
+            symbol: symbol.into(),
+            side,
+            order_type,
+            time_in_force: None,
+            quantity: None,
+            quote_order_qty: None,
+            price: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_client_order_id: None,
+            strategy_id: None,
+            strategy_type: None,
+            self_trade_prevention_mode: None,
+            new_order_resp_type: None,
+        }
+    }
+
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_quantity<F: Into<Number>>(mut self, quantity: F) -> Self {
+        self.quantity = Some(quantity.into());
+        self
+    }
+
+    pub fn set_quote_order_qty<F: Into<Number>>(mut self, quote_order_qty: F) -> Self {
+        self.quote_order_qty = Some(quote_order_qty.into());
+        self
+    }
+
+    pub fn set_price<F: Into<Number>>(mut self, price: F) -> Self {
+        self.price = Some(price.into());
+        self
+    }
+
+    pub fn set_stop_price<F: Into<Number>>(mut self, stop_price: F) -> Self {
+        self.stop_price = Some(stop_price.into());
+        self
+    }
+
+    pub fn set_trailing_delta(mut self, trailing_delta: u64) -> Self {
+        self.trailing_delta = Some(trailing_delta);
+        self
+    }
+
+    pub fn set_iceberg_qty<F: Into<Number>>(mut self, iceberg_qty: F) -> Self {
+        self.iceberg_qty = Some(iceberg_qty.into());
+        self
+    }
+
+    pub fn set_new_client_order_id<S: Into<String>>(mut self, new_client_order_id: S) -> Self {
+        self.new_client_order_id = Some(new_client_order_id.into());
+        self
+    }
+
+    pub fn set_strategy_id(mut self, strategy_id: u64) -> Self {
+        self.strategy_id = Some(strategy_id);
+        self
+    }
+
+    pub fn set_strategy_type(mut self, strategy_type: u64) -> Self {
+        self.strategy_type = Some(strategy_type);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    pub fn set_new_order_resp_type(mut self, new_order_resp_type: NewOrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    /// Validates the order, see `validate`.
+    pub fn build(self) -> Result<ValidatedSpotOrder> {
+        self.validate()?;
+        Ok(ValidatedSpotOrder(self))
+    }
+
+    /// Checks that the parameters required by the order type are set, and that none
+    /// it doesn't accept are.
+    pub fn validate(&self) -> Result<()> {
+        let order_type = &self.order_type;
+        let (limit, stop) = match order_type {
+            OrderType::Limit => (true, false),
+            OrderType::Market => (false, false),
+            OrderType::StopLoss | OrderType::TakeProfit => (false, true),
+            OrderType::StopLossLimit | OrderType::TakeProfitLimit => (true, true),
+            OrderType::LimitMaker => (true, false),
+        };
+        let is_market = matches!(order_type, OrderType::Market);
+        let needs_time_in_force = limit && !matches!(order_type, OrderType::LimitMaker);
+
+        if is_market {
+            if self.quantity.is_some() == self.quote_order_qty.is_some() {
+                bail!("MARKET orders need either quantity or quote_order_qty");
+            }
+        } else {
+            if self.quantity.is_none() {
+                bail!(format!("{} orders need a quantity", order_type));
+            }
+            if self.quote_order_qty.is_some() {
+                bail!("quote_order_qty is only accepted by MARKET orders");
+            }
+        }
+        if limit && self.price.is_none() {
+            bail!(format!("{} orders need a price", order_type));
+        }
+        if !limit && self.price.is_some() {
+            bail!(format!("{} orders don't take a price", order_type));
+        }
+        if needs_time_in_force && self.time_in_force.is_none() {
+            bail!(format!("{} orders need a time_in_force", order_type));
+        }
+        if !needs_time_in_force && self.time_in_force.is_some() {
+            bail!(format!("{} orders don't take a time_in_force", order_type));
+        }
+        if stop && self.stop_price.is_none() && self.trailing_delta.is_none() {
+            bail!(format!(
+                "{} orders need a stop_price or a trailing_delta",
+                order_type
+            ));
+        }
+        if !stop && (self.stop_price.is_some() || self.trailing_delta.is_some()) {
+            bail!(format!(
+                "{} orders don't take a stop_price or a trailing_delta",
+                order_type
+            ));
+        }
+        if self.iceberg_qty.is_some() {
+            if !limit {
+                bail!(format!("{} orders can't be iceberg orders", order_type));
+            }
+            if needs_time_in_force && !matches!(self.time_in_force, Some(TimeInForce::GTC)) {
+                bail!("Iceberg orders need time_in_force GTC");
+            }
+        }
+        if let Some(strategy_type) = self.strategy_type {
+            if strategy_type < 1_000_000 {
+                bail!("strategy_type must be at least 1000000");
+            }
+        }
+        Ok(())
+    }
+
+    /// Rounds the prices to the tick size and the quantity to the step size, then checks
+    /// the order against the symbol's filters. See `OrderValidator` for `reference_price`.
+    pub fn apply_filters(
+        mut self, validator: &OrderValidator, reference_price: Option<Number>,
+    ) -> Result<Self> {
+        let market = matches!(self.order_type, OrderType::Market);
+        self.price = self.price.map(|price| validator.round_price(price));
+        self.stop_price = self.stop_price.map(|price| validator.round_price(price));
+        if let Some(stop_price) = self.stop_price {
+            validator.validate_price(stop_price)?;
+        }
+        match (self.quantity, self.quote_order_qty) {
+            (Some(quantity), _) => {
+                let quantity = if market {
+                    validator.round_market_quantity(quantity)
+                } else {
+                    validator.round_quantity(quantity)
+                };
+                self.quantity = Some(quantity);
+                validator.validate(&self.side, self.price, quantity, reference_price)?;
+            }
+            (None, Some(quote_order_qty)) => validator.validate_notional(quote_order_qty, true)?,
+            (None, None) => (),
+        }
+        Ok(self)
+    }
+
+    fn into_parameters(self) -> BTreeMap<String, String> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), self.symbol);
+        parameters.insert("side".into(), self.side.to_string());
+        parameters.insert("type".into(), self.order_type.to_string());
+        if let Some(time_in_force) = self.time_in_force {
+            parameters.insert("timeInForce".into(), time_in_force.to_string());
+        }
+        if let Some(quantity) = self.quantity {
+            parameters.insert("quantity".into(), quantity.to_string());
+        }
+        if let Some(quote_order_qty) = self.quote_order_qty {
+            parameters.insert("quoteOrderQty".into(), quote_order_qty.to_string());
+        }
+        if let Some(price) = self.price {
+            parameters.insert("price".into(), price.to_string());
+        }
+        if let Some(stop_price) = self.stop_price {
+            parameters.insert("stopPrice".into(), stop_price.to_string());
+        }
+        if let Some(trailing_delta) = self.trailing_delta {
+            parameters.insert("trailingDelta".into(), trailing_delta.to_string());
+        }
+        if let Some(iceberg_qty) = self.iceberg_qty {
+            parameters.insert("icebergQty".into(), iceberg_qty.to_string());
+        }
+        parameters.insert(
+            "newClientOrderId".into(),
+            self.new_client_order_id.unwrap_or_else(uuid_spot),
+        );
+        if let Some(strategy_id) = self.strategy_id {
+            parameters.insert("strategyId".into(), strategy_id.to_string());
+        }
+        if let Some(strategy_type) = self.strategy_type {
+            parameters.insert("strategyType".into(), strategy_type.to_string());
+        }
+        if let Some(mode) = self.self_trade_prevention_mode {
+            parameters.insert("selfTradePreventionMode".into(), mode.to_string());
+        }
+        if let Some(new_order_resp_type) = self.new_order_resp_type {
+            parameters.insert("newOrderRespType".into(), new_order_resp_type.to_string());
+        }
+        parameters
+    }
+}
+
+/// A `SpotOrderRequest` that passed `validate`, built by `SpotOrderRequest::build`.
+pub struct ValidatedSpotOrder(SpotOrderRequest);
+
+impl ValidatedSpotOrder {
+    pub fn order(&self) -> &SpotOrderRequest {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> SpotOrderRequest {
+        self.0
+    }
+
+    // SOR only routes LIMIT and MARKET orders, and always by base quantity
+    fn into_sor_parameters(self) -> Result<BTreeMap<String, String>> {
+        if !matches!(self.0.order_type, OrderType::Limit | OrderType::Market) {
+            bail!(format!(
+                "{} orders can't be placed through SOR",
+                self.0.order_type
+            ));
+        }
+        if self.0.quote_order_qty.is_some() {
+            bail!("SOR orders need a quantity, not a quote_order_qty");
+        }
+        Ok(self.into_parameters())
+    }
+
+    fn into_parameters(self) -> BTreeMap<String, String> {
+        self.0.into_parameters()
+    }
+}
+
+pub enum CancelReplaceMode {
+    /// The new order isn't placed if the cancel fails
+    StopOnFailure,
+    /// The new order is placed whether the cancel succeeds or not
+    AllowFailure,
+}
+
+impl Display for CancelReplaceMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StopOnFailure => write!(f, "STOP_ON_FAILURE"),
+            Self::AllowFailure => write!(f, "ALLOW_FAILURE"),
+        }
+    }
+}
+
+/// Status the order to cancel must be in for the cancel to go through.
+pub enum CancelRestrictions {
+    OnlyNew,
+    OnlyPartiallyFilled,
+}
+
+impl Display for CancelRestrictions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OnlyNew => write!(f, "ONLY_NEW"),
+            Self::OnlyPartiallyFilled => write!(f, "ONLY_PARTIALLY_FILLED"),
+        }
+    }
+}
+
+/// What to do when the unfilled order count is exceeded.
+pub enum OrderRateLimitExceededMode {
+    DoNothing,
+    /// Still cancel the order
+    CancelOnly,
+}
+
+impl Display for OrderRateLimitExceededMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DoNothing => write!(f, "DO_NOTHING"),
+            Self::CancelOnly => write!(f, "CANCEL_ONLY"),
+        }
+    }
+}
+
+/// Cancels an order and places `order` on the same symbol, in one request.
+///
+/// The order to cancel is given by `cancel_order_id` or `cancel_orig_client_order_id`.
+pub struct CancelReplaceRequest {
+    pub order: ValidatedSpotOrder,
+    pub cancel_replace_mode: CancelReplaceMode,
+    pub cancel_order_id: Option<u64>,
+    pub cancel_orig_client_order_id: Option<String>,
+    /// New client order id of the canceled order, automatically generated if not sent.
+    pub cancel_new_client_order_id: Option<String>,
+    pub cancel_restrictions: Option<CancelRestrictions>,
+    pub order_rate_limit_exceeded_mode: Option<OrderRateLimitExceededMode>,
+}
+
+impl CancelReplaceRequest {
+    pub fn new(order: ValidatedSpotOrder, cancel_replace_mode: CancelReplaceMode) -> Self {
+        CancelReplaceRequest {
+            order,
+            cancel_replace_mode,
+            cancel_order_id: None,
+            cancel_orig_client_order_id: None,
+            cancel_new_client_order_id: None,
+            cancel_restrictions: None,
+            order_rate_limit_exceeded_mode: None,
+        }
+    }
+
+    pub fn set_cancel_order_id(mut self, cancel_order_id: u64) -> Self {
+        self.cancel_order_id = Some(cancel_order_id);
+        self
+    }
+
+    pub fn set_cancel_orig_client_order_id<S: Into<String>>(
+        mut self, cancel_orig_client_order_id: S,
+    ) -> Self {
+        self.cancel_orig_client_order_id = Some(cancel_orig_client_order_id.into());
+        self
+    }
+
+    pub fn set_cancel_new_client_order_id<S: Into<String>>(
+        mut self, cancel_new_client_order_id: S,
+    ) -> Self {
+        self.cancel_new_client_order_id = Some(cancel_new_client_order_id.into());
+        self
+    }
+
+    pub fn set_cancel_restrictions(mut self, cancel_restrictions: CancelRestrictions) -> Self {
+        self.cancel_restrictions = Some(cancel_restrictions);
+        self
+    }
+
+    pub fn set_order_rate_limit_exceeded_mode(mut self, mode: OrderRateLimitExceededMode) -> Self {
+        self.order_rate_limit_exceeded_mode = Some(mode);
+        self
+    }
+
+    fn into_parameters(self) -> Result<BTreeMap<String, String>> {
+        if self.cancel_order_id.is_none() && self.cancel_orig_client_order_id.is_none() {
+            bail!("Cancel-replace needs a cancel_order_id or a cancel_orig_client_order_id");
+        }
+
+        let mut parameters = self.order.into_parameters();
+        parameters.insert(
+            "cancelReplaceMode".into(),
+            self.cancel_replace_mode.to_string(),
+        );
+        if let Some(cancel_order_id) = self.cancel_order_id {
+            parameters.insert("cancelOrderId".into(), cancel_order_id.to_string());
+        }
+        if let Some(cancel_orig_client_order_id) = self.cancel_orig_client_order_id {
+            parameters.insert(
+                "cancelOrigClientOrderId".into(),
+                cancel_orig_client_order_id,
+            );
+        }
+        if let Some(cancel_new_client_order_id) = self.cancel_new_client_order_id {
+            parameters.insert("cancelNewClientOrderId".into(), cancel_new_client_order_id);
+        }
+        if let Some(cancel_restrictions) = self.cancel_restrictions {
+            parameters.insert("cancelRestrictions".into(), cancel_restrictions.to_string());
+        }
+        if let Some(mode) = self.order_rate_limit_exceeded_mode {
+            parameters.insert("orderRateLimitExceededMode".into(), mode.to_string());
+        }
+        Ok(parameters)
+    }
+}
+
+/// One order of an order list.
+///
+/// Which fields are needed depends on `order_type`, e.g. `price` and `time_in_force`
+/// for `LIMIT`, `stop_price` or `trailing_delta` for `STOP_LOSS`.
+pub struct OrderListLeg {
+    pub order_type: OrderType,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
+    /// Trailing stop, in basis points.
+    pub trailing_delta: Option<u64>,
+    pub time_in_force: Option<TimeInForce>,
+    pub iceberg_qty: Option<Number>,
+    /// Automatically generated if not sent.
+    pub client_order_id: Option<String>,
+}
+
+impl OrderListLeg {
+    pub fn new(order_type: OrderType) -> Self {
+        OrderListLeg {
+            order_type,
+            price: None,
+            stop_price: None,
+            trailing_delta: None,
+            time_in_force: None,
+            iceberg_qty: None,
+            client_order_id: None,
+        }
+    }
+}
+
+/// One-Cancels-the-Other: two orders, one above and one below the market price.
+/// When one of them is filled or touched, the other one is canceled.
+///
+/// `above` is a `LIMIT_MAKER`, `TAKE_PROFIT`, `TAKE_PROFIT_LIMIT`, `STOP_LOSS` or
+/// `STOP_LOSS_LIMIT` order, `below` too.
+pub struct OcoOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: Number,
+    pub above: OrderListLeg,
+    pub below: OrderListLeg,
+    pub list_client_order_id: Option<String>,
+}
+
+/// One-Triggers-the-Other: the pending order is placed once the working order is filled.
+pub struct OtoOrderRequest {
+    pub symbol: String,
+    pub working_side: OrderSide,
+    pub working_quantity: Number,
+    /// `LIMIT` or `LIMIT_MAKER`
+    pub working: OrderListLeg,
+    pub pending_side: OrderSide,
+    pub pending_quantity: Number,
+    pub pending: OrderListLeg,
+    pub list_client_order_id: Option<String>,
+}
+
+/// One-Triggers-a-One-Cancels-the-Other: the pending OCO is placed once the working order
+/// is filled.
+pub struct OtocoOrderRequest {
+    pub symbol: String,
+    pub working_side: OrderSide,
+    pub working_quantity: Number,
+    /// `LIMIT` or `LIMIT_MAKER`
+    pub working: OrderListLeg,
+    pub pending_side: OrderSide,
+    pub pending_quantity: Number,
+    pub pending_above: OrderListLeg,
+    /// Optional, the pending list then has a single order
+    pub pending_below: Option<OrderListLeg>,
+    pub list_client_order_id: Option<String>,
+}
+
+// Parameters of an order list leg, prefixed, e.g. `abovePrice` or `pendingBelowStopPrice`.
+fn insert_leg(parameters: &mut BTreeMap<String, String>, prefix: &str, leg: OrderListLeg) {
+    parameters.insert(format!("{}Type", prefix), leg.order_type.to_string());
+    if let Some(price) = leg.price {
+        parameters.insert(format!("{}Price", prefix), price.to_string());
+    }
+    if let Some(stop_price) = leg.stop_price {
+        parameters.insert(format!("{}StopPrice", prefix), stop_price.to_string());
+    }
+    if let Some(trailing_delta) = leg.trailing_delta {
+        parameters.insert(
+            format!("{}TrailingDelta", prefix),
+            trailing_delta.to_string(),
+        );
+    }
+    if let Some(time_in_force) = leg.time_in_force {
+        parameters.insert(format!("{}TimeInForce", prefix), time_in_force.to_string());
+    }
+    if let Some(iceberg_qty) = leg.iceberg_qty {
+        parameters.insert(format!("{}IcebergQty", prefix), iceberg_qty.to_string());
+    }
+    parameters.insert(
+        format!("{}ClientOrderId", prefix),
+        leg.client_order_id.unwrap_or_else(uuid_spot),
+    );
+}
+
+fn order_list_parameters(
+    symbol: String, list_client_order_id: Option<String>,
+) -> BTreeMap<String, String> {
+    let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    if let Some(list_client_order_id) = list_client_order_id {
+        parameters.insert("listClientOrderId".into(), list_client_order_id);
+    }
+    parameters
+}
+
+// The async twin of `Account` is generated by renaming every `Account` ident,
+// which would also rename `Spot::Account`, so the endpoint is built out here.
+fn account_endpoint() -> API {
+    API::Spot(Spot::Account)
+}
+
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncAccount")
+)]
 impl Account {
     // Account Information
-    pub fn get_account(&self) -> Result<AccountInformation> {
+    pub async fn get_account(&self) -> Result<AccountInformation> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(account_endpoint(), Some(request))
+            .await
+    }
+
+    // Account information, leaving out the assets with a zero balance
+    pub async fn get_account_omit_zero_balances(&self) -> Result<AccountInformation> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("omitZeroBalances".into(), "true".into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(account_endpoint(), Some(request))
+            .await
+    }
+
+    // Commission rates of the account for ONE symbol, with the BNB discount
+    pub async fn get_commission_rates<S>(&self, symbol: S) -> Result<AccountCommission>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AccountCommission), Some(request))
+            .await
+    }
+
+    // Unfilled orders placed in every ORDERS rate limit interval
+    pub async fn get_unfilled_order_count(&self) -> Result<Vec<UnfilledOrderCount>> {
         let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
         self.client
-            .get_signed(API::Spot(Spot::Account), Some(request))
+            .get_signed(API::Spot(Spot::RateLimitOrder), Some(request))
+            .await
     }
 
     // Balance for a single Asset
-    pub fn get_balance<S>(&self, asset: S) -> Result<Balance>
+    pub async fn get_balance<S>(&self, asset: S) -> Result<Balance>
     where
         S: Into<String>,
     {
-        match self.get_account() {
+        match self.get_account().await {
             Ok(account) => {
                 let cmp_asset = asset.into();
                 for balance in account.balances {
@@ -146,7 +782,7 @@ impl Account {
     }
 
     // Current open orders for ONE symbol
-    pub fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
     where
         S: Into<String>,
     {
@@ -156,19 +792,21 @@ impl Account {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::OpenOrders), Some(request))
+            .await
     }
 
     // All current open orders
-    pub fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+    pub async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
         let parameters: BTreeMap<String, String> = BTreeMap::new();
 
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::OpenOrders), Some(request))
+            .await
     }
 
     // Cancel all open orders for a single symbol
-    pub fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<Vec<OrderCanceled>>
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<Vec<OrderCanceled>>
     where
         S: Into<String>,
     {
@@ -177,10 +815,11 @@ impl Account {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed(API::Spot(Spot::OpenOrders), Some(request))
+            .await
     }
 
     // Check an order's status
-    pub fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    pub async fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
     where
         S: Into<String>,
     {
@@ -191,12 +830,64 @@ impl Account {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    // Check an order's status by the client order id it was placed with
+    pub async fn order_status_with_client_id<S, C>(
+        &self, symbol: S, orig_client_order_id: C,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+        C: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    /// All orders of a symbol: active, canceled or filled.
+    ///
+    /// From `order_id` on when set, otherwise the most recent ones; `start_time` and
+    /// `end_time` are at most 24 hours apart, `limit` defaults to 500 (max 1000).
+    pub async fn get_all_orders<S, F, N>(
+        &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = order_id.into() {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrders), Some(request))
+            .await
     }
 
     /// Place a test status order
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_order_status<S>(&self, symbol: S, order_id: u64) -> Result<()>
+    pub async fn test_order_status<S>(&self, symbol: S, order_id: u64) -> Result<()>
     where
         S: Into<String>,
     {
@@ -207,19 +898,21 @@ impl Account {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed::<Empty>(API::Spot(Spot::OrderTest), Some(request))
+            .await
             .map(|_| ())
     }
 
     // Place a LIMIT order - BUY
-    pub fn limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
+    pub async fn limit_buy<S, F, P>(&self, symbol: S, qty: F, price: P) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
+            price: Some(price.into()),
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Limit,
@@ -228,21 +921,24 @@ impl Account {
         };
         let order = self.build_order(buy, None);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
     }
 
     /// Place a test limit order - BUY
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    pub async fn test_limit_buy<S, F, P>(&self, symbol: S, qty: F, price: P) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
+            price: Some(price.into()),
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Limit,
@@ -253,19 +949,21 @@ impl Account {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
             .map(|_| ())
     }
 
     // Place a LIMIT order - SELL
-    pub fn limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
+    pub async fn limit_sell<S, F, P>(&self, symbol: S, qty: F, price: P) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
+            price: Some(price.into()),
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Limit,
@@ -274,21 +972,24 @@ impl Account {
         };
         let order = self.build_order(sell, None);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
     }
 
     /// Place a test LIMIT order - SELL
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    pub async fn test_limit_sell<S, F, P>(&self, symbol: S, qty: F, price: P) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
+            price: Some(price.into()),
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Limit,
@@ -299,19 +1000,20 @@ impl Account {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
             .map(|_| ())
     }
 
     // Place a MARKET order - BUY
-    pub fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price: 0.0,
+            price: None,
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
@@ -320,21 +1022,23 @@ impl Account {
         };
         let order = self.build_order(buy, None);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
     }
 
     /// Place a test MARKET order - BUY
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_market_buy<S, F>(&self, symbol: S, qty: F) -> Result<()>
+    pub async fn test_market_buy<S, F>(&self, symbol: S, qty: F) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price: 0.0,
+            price: None,
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
@@ -345,67 +1049,67 @@ impl Account {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
             .map(|_| ())
     }
 
     // Place a MARKET order with quote quantity - BUY
-    pub fn market_buy_using_quote_quantity<S, F>(
+    pub async fn market_buy_using_quote_quantity<S, F>(
         &self, symbol: S, quote_order_qty: F,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
             quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
         let order = self.build_quote_quantity_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
     }
 
     /// Place a test MARKET order with quote quantity - BUY
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_market_buy_using_quote_quantity<S, F>(
+    pub async fn test_market_buy_using_quote_quantity<S, F>(
         &self, symbol: S, quote_order_qty: F,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
             quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
         let order = self.build_quote_quantity_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
             .map(|_| ())
     }
 
     // Place a MARKET order - SELL
-    pub fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price: 0.0,
+            price: None,
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
@@ -414,21 +1118,23 @@ impl Account {
         };
         let order = self.build_order(sell, None);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
     }
 
     /// Place a test MARKET order - SELL
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_market_sell<S, F>(&self, symbol: S, qty: F) -> Result<()>
+    pub async fn test_market_sell<S, F>(&self, symbol: S, qty: F) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price: 0.0,
+            price: None,
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
@@ -439,54 +1145,54 @@ impl Account {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
             .map(|_| ())
     }
 
     // Place a MARKET order with quote quantity - SELL
-    pub fn market_sell_using_quote_quantity<S, F>(
+    pub async fn market_sell_using_quote_quantity<S, F>(
         &self, symbol: S, quote_order_qty: F,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
             quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
         let order = self.build_quote_quantity_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
     }
 
     /// Place a test MARKET order with quote quantity - SELL
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_market_sell_using_quote_quantity<S, F>(
+    pub async fn test_market_sell_using_quote_quantity<S, F>(
         &self, symbol: S, quote_order_qty: F,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
             quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
-            time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
         let order = self.build_quote_quantity_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
             .map(|_| ())
     }
 
@@ -496,26 +1202,28 @@ impl Account {
     ///```no_run
     /// use binance::api::Binance;
     /// use binance::account::*;
+    /// use binance::model::to_number;
     ///
     /// fn main() {
     ///     let api_key = Some("api_key".into());
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
-    ///     let result = account.stop_limit_buy_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC);
+    ///     let result = account.stop_limit_buy_order("LTCBTC", 1, to_number(0.1), to_number(0.09), TimeInForce::GTC);
     /// }
     /// ```
-    pub fn stop_limit_buy_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+    pub async fn stop_limit_buy_order<S, F, P>(
+        &self, symbol: S, qty: F, price: P, stop_price: P, time_in_force: TimeInForce,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
+            price: Some(price.into()),
+            stop_price: Some(stop_price.into()),
             order_side: OrderSide::Buy,
             order_type: OrderType::StopLossLimit,
             time_in_force,
@@ -523,7 +1231,9 @@ impl Account {
         };
         let order = self.build_order(sell, None);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
     }
 
     /// Create a stop limit buy test order for the given symbol, price and stop price.
@@ -534,26 +1244,28 @@ impl Account {
     ///```no_run
     /// use binance::api::Binance;
     /// use binance::account::*;
+    /// use binance::model::to_number;
     ///
     /// fn main() {
     ///     let api_key = Some("api_key".into());
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
-    ///     let result = account.test_stop_limit_buy_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC);
+    ///     let result = account.test_stop_limit_buy_order("LTCBTC", 1, to_number(0.1), to_number(0.09), TimeInForce::GTC);
     /// }
     /// ```
-    pub fn test_stop_limit_buy_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+    pub async fn test_stop_limit_buy_order<S, F, P>(
+        &self, symbol: S, qty: F, price: P, stop_price: P, time_in_force: TimeInForce,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
+            price: Some(price.into()),
+            stop_price: Some(stop_price.into()),
             order_side: OrderSide::Buy,
             order_type: OrderType::StopLossLimit,
             time_in_force,
@@ -563,6 +1275,7 @@ impl Account {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
             .map(|_| ())
     }
 
@@ -572,26 +1285,28 @@ impl Account {
     ///```no_run
     /// use binance::api::Binance;
     /// use binance::account::*;
+    /// use binance::model::to_number;
     ///
     /// fn main() {
     ///     let api_key = Some("api_key".into());
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
-    ///     let result = account.stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC);
+    ///     let result = account.stop_limit_sell_order("LTCBTC", 1, to_number(0.1), to_number(0.09), TimeInForce::GTC);
     /// }
     /// ```
-    pub fn stop_limit_sell_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+    pub async fn stop_limit_sell_order<S, F, P>(
+        &self, symbol: S, qty: F, price: P, stop_price: P, time_in_force: TimeInForce,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
+            price: Some(price.into()),
+            stop_price: Some(stop_price.into()),
             order_side: OrderSide::Sell,
             order_type: OrderType::StopLossLimit,
             time_in_force,
@@ -599,7 +1314,9 @@ impl Account {
         };
         let order = self.build_order(sell, None);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
     }
 
     /// Create a stop limit sell order for the given symbol, price and stop price.
@@ -610,26 +1327,28 @@ impl Account {
     ///```no_run
     /// use binance::api::Binance;
     /// use binance::account::*;
+    /// use binance::model::to_number;
     ///
     /// fn main() {
     ///     let api_key = Some("api_key".into());
     ///     let secret_key = Some("secret_key".into());
     ///     let account: Account = Binance::new(api_key, secret_key);
-    ///     let result = account.test_stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC);
+    ///     let result = account.test_stop_limit_sell_order("LTCBTC", 1, to_number(0.1), to_number(0.09), TimeInForce::GTC);
     /// }
     /// ```
-    pub fn test_stop_limit_sell_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+    pub async fn test_stop_limit_sell_order<S, F, P>(
+        &self, symbol: S, qty: F, price: P, stop_price: P, time_in_force: TimeInForce,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
+            price: Some(price.into()),
+            stop_price: Some(stop_price.into()),
             order_side: OrderSide::Sell,
             order_type: OrderType::StopLossLimit,
             time_in_force,
@@ -639,18 +1358,20 @@ impl Account {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
             .map(|_| ())
     }
 
     /// Place a custom order
     #[allow(clippy::too_many_arguments)]
-    pub fn custom_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: Option<f64>, order_side: OrderSide,
+    pub async fn custom_order<S, F, P>(
+        &self, symbol: S, qty: F, price: P, stop_price: Option<P>, order_side: OrderSide,
         order_type: OrderType, time_in_force: TimeInForce, new_client_order_id: Option<String>,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         self.custom_order_with_params(
             symbol,
@@ -663,24 +1384,26 @@ impl Account {
             new_client_order_id,
             BTreeMap::new(),
         )
+        .await
     }
 
     /// Place a custom order
     #[allow(clippy::too_many_arguments)]
-    pub fn custom_order_with_params<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: Option<f64>, order_side: OrderSide,
+    pub async fn custom_order_with_params<S, F, P>(
+        &self, symbol: S, qty: F, price: P, stop_price: Option<P>, order_side: OrderSide,
         order_type: OrderType, time_in_force: TimeInForce, new_client_order_id: Option<String>,
         request_params: BTreeMap<String, String>,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
-            stop_price,
+            price: Some(price.into()),
+            stop_price: stop_price.map(Into::into),
             order_side,
             order_type,
             time_in_force,
@@ -688,26 +1411,29 @@ impl Account {
         };
         let order = self.build_order(sell, Some(request_params));
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
     }
 
     /// Place a test custom order
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
     #[allow(clippy::too_many_arguments)]
-    pub fn test_custom_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: Option<f64>, order_side: OrderSide,
+    pub async fn test_custom_order<S, F, P>(
+        &self, symbol: S, qty: F, price: P, stop_price: Option<P>, order_side: OrderSide,
         order_type: OrderType, time_in_force: TimeInForce, new_client_order_id: Option<String>,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
+        P: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
             qty: qty.into(),
-            price,
-            stop_price,
+            price: Some(price.into()),
+            stop_price: stop_price.map(Into::into),
             order_side,
             order_type,
             time_in_force,
@@ -717,11 +1443,152 @@ impl Account {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Place an order of any type
+    ///
+    /// With `NewOrderResponseType::Ack`, only the ids and `transact_time` of the returned
+    /// `Transaction` are filled.
+    pub async fn place_order(&self, order: ValidatedSpotOrder) -> Result<Transaction> {
+        let order = order.into_parameters();
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test order of any type
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_place_order(&self, order: ValidatedSpotOrder) -> Result<()> {
+        let order = order.into_parameters();
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Place a LIMIT or MARKET order through Smart Order Routing (SOR)
+    ///
+    /// The order may be filled on the other symbols that share its base asset, see
+    /// `ExchangeInformation::sors`.
+    pub async fn place_sor_order(&self, order: ValidatedSpotOrder) -> Result<SorTransaction> {
+        let order = order.into_sor_parameters()?;
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::SorOrder), request)
+            .await
+    }
+
+    /// Place a test SOR order
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_place_sor_order(&self, order: ValidatedSpotOrder) -> Result<()> {
+        let order = order.into_sor_parameters()?;
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::SorOrderTest), request)
+            .await
             .map(|_| ())
     }
 
+    /// Cancel an order and place a new one, in one request
+    ///
+    /// When one or both halves fail (errors -2021 and -2022), the outcome of each is
+    /// returned in `ErrorKind::CancelReplaceFailed`.
+    pub async fn cancel_replace(
+        &self, request: CancelReplaceRequest,
+    ) -> Result<OrderCancelReplace> {
+        let order = request.into_parameters()?;
+        let request = build_signed_request(order, self.recv_window)?;
+        let result = self
+            .client
+            .post_signed(API::Spot(Spot::OrderCancelReplace), request)
+            .await;
+        match result {
+            Err(Error(ErrorKind::BinanceError(response), state)) => {
+                let outcome = match response.code {
+                    -2021 | -2022 => response
+                        .data
+                        .clone()
+                        .and_then(|data| serde_json::from_value(data).ok()),
+                    _ => None,
+                };
+                match outcome {
+                    Some(outcome) => {
+                        Err(ErrorKind::CancelReplaceFailed(response, Box::new(outcome)).into())
+                    }
+                    None => Err(Error(ErrorKind::BinanceError(response), state)),
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Reduce the quantity of an open order, keeping its place in the order book
+    pub async fn amend_order_keep_priority<S, F>(
+        &self, symbol: S, order_id: u64, new_qty: F,
+    ) -> Result<OrderAmendment>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        parameters.insert("newQty".into(), new_qty.into().to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .put_signed(API::Spot(Spot::OrderAmendKeepPriority), request)
+            .await
+    }
+
+    /// Reduce the quantity of an open order given by its client order id, keeping its
+    /// place in the order book
+    pub async fn amend_order_keep_priority_with_client_id<S, C, F>(
+        &self, symbol: S, orig_client_order_id: C, new_qty: F,
+    ) -> Result<OrderAmendment>
+    where
+        S: Into<String>,
+        C: Into<String>,
+        F: Into<Number>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id.into());
+        parameters.insert("newQty".into(), new_qty.into().to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .put_signed(API::Spot(Spot::OrderAmendKeepPriority), request)
+            .await
+    }
+
+    /// Validator for the filters of `symbol`, from the exchange information
+    pub async fn order_validator<S>(&self, symbol: S) -> Result<OrderValidator>
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into().to_uppercase();
+        let info: ExchangeInformation = self
+            .client
+            .get(
+                API::Spot(Spot::ExchangeInfo),
+                Some(format!("symbol={}", symbol)),
+            )
+            .await?;
+        match info.symbols.iter().find(|item| item.symbol == symbol) {
+            Some(item) => OrderValidator::from_symbol(item),
+            None => bail!("Symbol not found"),
+        }
+    }
+
     // Check an order's status
-    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<OrderCanceled>
+    pub async fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<OrderCanceled>
     where
         S: Into<String>,
     {
@@ -732,28 +1599,31 @@ impl Account {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed(API::Spot(Spot::Order), Some(request))
+            .await
     }
 
-    pub fn cancel_order_with_client_id<S>(
-        &self, symbol: S, orig_client_order_id: String,
+    // Cancel an order by the client order id it was placed with
+    pub async fn cancel_order_with_client_id<S, C>(
+        &self, symbol: S, orig_client_order_id: C,
     ) -> Result<OrderCanceled>
     where
         S: Into<String>,
+        C: Into<String>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        parameters.insert("origClientOrderId".into(), orig_client_order_id.into());
 
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed(API::Spot(Spot::Order), Some(request))
+            .await
     }
 
-    pub fn cancel_order_with_client_id_rs<S>() {}
     /// Place a test cancel order
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<()>
+    pub async fn test_cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<()>
     where
         S: Into<String>,
     {
@@ -763,11 +1633,103 @@ impl Account {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed::<Empty>(API::Spot(Spot::OrderTest), Some(request))
+            .await
             .map(|_| ())
     }
 
+    /// Place an OCO order list
+    pub async fn oco_order(&self, order: OcoOrderRequest) -> Result<OrderList> {
+        let mut parameters = order_list_parameters(order.symbol, order.list_client_order_id);
+        parameters.insert("side".into(), order.side.to_string());
+        parameters.insert("quantity".into(), order.quantity.to_string());
+        insert_leg(&mut parameters, "above", order.above);
+        insert_leg(&mut parameters, "below", order.below);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::OrderListOco), request)
+            .await
+    }
+
+    /// Place an OTO order list
+    pub async fn oto_order(&self, order: OtoOrderRequest) -> Result<OrderList> {
+        let mut parameters = order_list_parameters(order.symbol, order.list_client_order_id);
+        parameters.insert("workingSide".into(), order.working_side.to_string());
+        parameters.insert("workingQuantity".into(), order.working_quantity.to_string());
+        insert_leg(&mut parameters, "working", order.working);
+        parameters.insert("pendingSide".into(), order.pending_side.to_string());
+        parameters.insert("pendingQuantity".into(), order.pending_quantity.to_string());
+        insert_leg(&mut parameters, "pending", order.pending);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::OrderListOto), request)
+            .await
+    }
+
+    /// Place an OTOCO order list
+    pub async fn otoco_order(&self, order: OtocoOrderRequest) -> Result<OrderList> {
+        let mut parameters = order_list_parameters(order.symbol, order.list_client_order_id);
+        parameters.insert("workingSide".into(), order.working_side.to_string());
+        parameters.insert("workingQuantity".into(), order.working_quantity.to_string());
+        insert_leg(&mut parameters, "working", order.working);
+        parameters.insert("pendingSide".into(), order.pending_side.to_string());
+        parameters.insert("pendingQuantity".into(), order.pending_quantity.to_string());
+        insert_leg(&mut parameters, "pendingAbove", order.pending_above);
+        if let Some(pending_below) = order.pending_below {
+            insert_leg(&mut parameters, "pendingBelow", pending_below);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Spot(Spot::OrderListOtoco), request)
+            .await
+    }
+
+    // Check an order list's status
+    pub async fn order_list_status(&self, order_list_id: u64) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    // All order lists, the most recent ones
+    pub async fn all_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrderList), Some(request))
+            .await
+    }
+
+    // Current open order lists
+    pub async fn open_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrderList), Some(request))
+            .await
+    }
+
+    // Cancel an entire order list
+    pub async fn cancel_order_list<S>(&self, symbol: S, order_list_id: u64) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
     // Trade history
-    pub fn trade_history<S>(&self, symbol: S) -> Result<Vec<TradeHistory>>
+    pub async fn trade_history<S>(&self, symbol: S) -> Result<Vec<TradeHistory>>
     where
         S: Into<String>,
     {
@@ -777,15 +1739,18 @@ impl Account {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::MyTrades), Some(request))
+            .await
     }
 
     // Trade history starting from selected date
-    pub fn trade_history_from<S>(&self, symbol: S, start_time: u64) -> Result<Vec<TradeHistory>>
+    pub async fn trade_history_from<S>(
+        &self, symbol: S, start_time: u64,
+    ) -> Result<Vec<TradeHistory>>
     where
         S: Into<String>,
     {
         if !is_start_time_valid(&start_time) {
-            return bail!("Start time should be less than the current time");
+            bail!("Start time should be less than the current time");
         }
 
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
@@ -794,32 +1759,107 @@ impl Account {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Spot(Spot::MyTrades), Some(request))
+            .await
     }
 
     // Trade history starting from selected time to some time
-    pub fn trade_history_from_to<S>(
+    pub async fn trade_history_from_to<S>(
         &self, symbol: S, start_time: u64, end_time: u64,
     ) -> Result<Vec<TradeHistory>>
     where
         S: Into<String>,
     {
         if end_time <= start_time {
-            return bail!("End time should be greater than start time");
+            bail!("End time should be greater than start time");
         }
         if !is_start_time_valid(&start_time) {
-            return bail!("Start time should be less than the current time");
+            bail!("Start time should be less than the current time");
         }
-        self.get_trades(symbol, start_time, end_time)
+        self.get_trades(symbol, start_time, end_time).await
     }
 
-    fn get_trades<S>(&self, symbol: S, start_time: u64, end_time: u64) -> Result<Vec<TradeHistory>>
+    // Orders expired by self-trade prevention, for ONE prevented match
+    pub async fn prevented_match<S>(
+        &self, symbol: S, prevented_match_id: u64,
+    ) -> Result<Vec<PreventedMatch>>
     where
         S: Into<String>,
     {
-        let mut trades = match self.trade_history_from(symbol, start_time) {
-            Ok(trades) => trades,
-            Err(e) => return Err(e),
-        };
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("preventedMatchId".into(), prevented_match_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyPreventedMatches), Some(request))
+            .await
+    }
+
+    // Orders expired by self-trade prevention, for ONE taker or maker order
+    pub async fn prevented_matches<S, F, N>(
+        &self, symbol: S, order_id: u64, from_prevented_match_id: F, limit: N,
+    ) -> Result<Vec<PreventedMatch>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        if let Some(from_id) = from_prevented_match_id.into() {
+            parameters.insert("fromPreventedMatchId".into(), from_id.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyPreventedMatches), Some(request))
+            .await
+    }
+
+    // Allocations from SOR order placement, optionally for ONE order
+    pub async fn allocations<S, F, N>(
+        &self, symbol: S, order_id: F, from_allocation_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<Allocation>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = order_id.into() {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(from_id) = from_allocation_id.into() {
+            parameters.insert("fromAllocationId".into(), from_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyAllocations), Some(request))
+            .await
+    }
+
+    async fn get_trades<S>(
+        &self, symbol: S, start_time: u64, end_time: u64,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+    {
+        let mut trades = self.trade_history_from(symbol, start_time).await?;
         trades.retain(|trade| trade.time <= end_time);
         Ok(trades)
     }
@@ -838,8 +1878,9 @@ impl Account {
             order_parameters.insert("stopPrice".into(), stop_price.to_string());
         }
 
-        if order.price != 0.0 {
-            order_parameters.insert("price".into(), order.price.to_string());
+        // a zero price, as passed to `custom_order` for MARKET orders, isn't sent
+        if let Some(price) = order.price.filter(|price| *price != Number::default()) {
+            order_parameters.insert("price".into(), price.to_string());
             order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
         }
 
@@ -852,7 +1893,7 @@ impl Account {
 
         if let Some(params) = request_params {
             for (key, value) in params {
-                order_parameters.insert(key, value.to_string());
+                order_parameters.insert(key, value);
             }
         }
 
@@ -869,11 +1910,6 @@ impl Account {
         order_parameters.insert("type".into(), order.order_type.to_string());
         order_parameters.insert("quoteOrderQty".into(), order.quote_order_qty.to_string());
 
-        if order.price != 0.0 {
-            order_parameters.insert("price".into(), order.price.to_string());
-            order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
-        }
-
         if let Some(client_order_id) = order.new_client_order_id {
             order_parameters.insert("newClientOrderId".into(), client_order_id);
         } else {
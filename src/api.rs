@@ -1,10 +1,39 @@
 use crate::account::Account;
 use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::account::AsyncAccount;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+#[cfg(feature = "async")]
+use crate::futures::account::AsyncFuturesAccount;
+#[cfg(feature = "async")]
+use crate::futures::coinm::account::AsyncCoinMAccount;
+#[cfg(feature = "async")]
+use crate::futures::coinm::general::AsyncCoinMGeneral;
+#[cfg(feature = "async")]
+use crate::futures::coinm::market::AsyncCoinMMarket;
+#[cfg(feature = "async")]
+use crate::futures::general::AsyncFuturesGeneral;
+#[cfg(feature = "async")]
+use crate::futures::market::AsyncFuturesMarket;
+#[cfg(feature = "async")]
+use crate::futures::userstream::AsyncFuturesUserStream;
+#[cfg(feature = "async")]
+use crate::general::AsyncGeneral;
+#[cfg(feature = "async")]
+use crate::market::AsyncMarket;
+#[cfg(feature = "async")]
+use crate::savings::AsyncSavings;
+#[cfg(feature = "async")]
+use crate::userstream::AsyncUserStream;
 use crate::config::{
-    Config, FUTURES_MAINNET, FUTURES_TESTNET, FUTURES_WS_MAINNET, FUTURES_WS_TESTNET, SPOT_MAINNET,
-    SPOT_TESTNET, SPOT_WS_MAINNET, SPOT_WS_TESTNET,
+    Config, COINM_MAINNET, COINM_TESTNET, FUTURES_MAINNET, FUTURES_TESTNET, FUTURES_WS_MAINNET,
+    FUTURES_WS_TESTNET, SPOT_MAINNET, SPOT_TESTNET, SPOT_WS_MAINNET, SPOT_WS_TESTNET,
 };
 use crate::futures::account::FuturesAccount;
+use crate::futures::coinm::account::CoinMAccount;
+use crate::futures::coinm::general::CoinMGeneral;
+use crate::futures::coinm::market::CoinMMarket;
 use crate::futures::general::FuturesGeneral;
 use crate::futures::market::FuturesMarket;
 use crate::futures::userstream::FuturesUserStream;
@@ -18,6 +47,7 @@ pub enum API {
     Spot(Spot),
     Savings(Sapi),
     Futures(Futures),
+    CoinM(CoinM),
 }
 
 /// Endpoint for production and test orders.
@@ -34,18 +64,32 @@ pub enum Spot {
     Klines,
     AvgPrice,
     Ticker24hr,
+    Ticker,
+    TickerTradingDay,
+    UiKlines,
     Price,
     BookTicker,
     Order,
     OrderTest,
+    OrderCancelReplace,
+    OrderAmendKeepPriority,
+    SorOrder,
+    SorOrderTest,
     OpenOrders,
     AllOrders,
     Oco,
+    OrderListOco,
+    OrderListOto,
+    OrderListOtoco,
     OrderList,
     AllOrderList,
     OpenOrderList,
     Account,
+    AccountCommission,
+    RateLimitOrder,
     MyTrades,
+    MyPreventedMatches,
+    MyAllocations,
     UserDataStream,
 }
 
@@ -68,8 +112,10 @@ pub enum Futures {
     ContinuousKlines,
     IndexPriceKlines,
     MarkPriceKlines,
+    PremiumIndexKlines,
     PremiumIndex,
     FundingRate,
+    FundingInfo,
     Ticker24hr,
     TickerPrice,
     BookTicker,
@@ -88,6 +134,7 @@ pub enum Futures {
     TopLongShortPositionRatio,
     GlobalLongShortAccountRatio,
     TakerlongshortRatio,
+    Basis,
     LvtKlines,
     IndexInfo,
     ChangeInitialLeverage,
@@ -99,6 +146,66 @@ pub enum Futures {
     Income,
 }
 
+/// COIN-M futures, on `dapi.binance.com`.
+pub enum CoinM {
+    Ping,
+    Time,
+    ExchangeInfo,
+    Depth,
+    Trades,
+    AggTrades,
+    Klines,
+    ContinuousKlines,
+    IndexPriceKlines,
+    MarkPriceKlines,
+    PremiumIndex,
+    FundingRate,
+    Ticker24hr,
+    TickerPrice,
+    BookTicker,
+    OpenInterest,
+    Order,
+    OpenOrders,
+    AllOpenOrders,
+    AllOrders,
+    UserTrades,
+    PositionRisk,
+    Account,
+    Balance,
+    ChangeInitialLeverage,
+    MarginType,
+    PositionMargin,
+    PositionSide,
+}
+
+impl API {
+    /// Whether a POST to this endpoint creates orders, which count towards the order rate limits.
+    pub(crate) fn places_order(&self) -> bool {
+        matches!(
+            self,
+            API::Spot(
+                Spot::Order
+                    | Spot::OrderCancelReplace
+                    | Spot::SorOrder
+                    | Spot::Oco
+                    | Spot::OrderListOco
+                    | Spot::OrderListOto
+                    | Spot::OrderListOtoco
+            ) | API::Futures(Futures::Order | Futures::AlgoOrder)
+                | API::CoinM(CoinM::Order)
+        )
+    }
+
+    /// The server time endpoint of the API this endpoint belongs to.
+    pub(crate) fn time_endpoint(&self) -> API {
+        match self {
+            API::Futures(_) => API::Futures(Futures::Time),
+            API::CoinM(_) => API::CoinM(CoinM::Time),
+            _ => API::Spot(Spot::Time),
+        }
+    }
+}
+
 impl From<API> for String {
     fn from(item: API) -> Self {
         String::from(match item {
@@ -113,18 +220,32 @@ impl From<API> for String {
                 Spot::Klines => "/api/v3/klines",
                 Spot::AvgPrice => "/api/v3/avgPrice",
                 Spot::Ticker24hr => "/api/v3/ticker/24hr",
+                Spot::Ticker => "/api/v3/ticker",
+                Spot::TickerTradingDay => "/api/v3/ticker/tradingDay",
+                Spot::UiKlines => "/api/v3/uiKlines",
                 Spot::Price => "/api/v3/ticker/price",
                 Spot::BookTicker => "/api/v3/ticker/bookTicker",
                 Spot::Order => "/api/v3/order",
                 Spot::OrderTest => "/api/v3/order/test",
+                Spot::OrderCancelReplace => "/api/v3/order/cancelReplace",
+                Spot::OrderAmendKeepPriority => "/api/v3/order/amend/keepPriority",
+                Spot::SorOrder => "/api/v3/sor/order",
+                Spot::SorOrderTest => "/api/v3/sor/order/test",
                 Spot::OpenOrders => "/api/v3/openOrders",
                 Spot::AllOrders => "/api/v3/allOrders",
                 Spot::Oco => "/api/v3/order/oco",
+                Spot::OrderListOco => "/api/v3/orderList/oco",
+                Spot::OrderListOto => "/api/v3/orderList/oto",
+                Spot::OrderListOtoco => "/api/v3/orderList/otoco",
                 Spot::OrderList => "/api/v3/orderList",
                 Spot::AllOrderList => "/api/v3/allOrderList",
                 Spot::OpenOrderList => "/api/v3/openOrderList",
                 Spot::Account => "/api/v3/account",
+                Spot::AccountCommission => "/api/v3/account/commission",
+                Spot::RateLimitOrder => "/api/v3/rateLimit/order",
                 Spot::MyTrades => "/api/v3/myTrades",
+                Spot::MyPreventedMatches => "/api/v3/myPreventedMatches",
+                Spot::MyAllocations => "/api/v3/myAllocations",
                 Spot::UserDataStream => "/api/v3/userDataStream",
             },
             API::Savings(route) => match route {
@@ -145,8 +266,10 @@ impl From<API> for String {
                 Futures::ContinuousKlines => "/fapi/v1/continuousKlines",
                 Futures::IndexPriceKlines => "/fapi/v1/indexPriceKlines",
                 Futures::MarkPriceKlines => "/fapi/v1/markPriceKlines",
+                Futures::PremiumIndexKlines => "/fapi/v1/premiumIndexKlines",
                 Futures::PremiumIndex => "/fapi/v1/premiumIndex",
                 Futures::FundingRate => "/fapi/v1/fundingRate",
+                Futures::FundingInfo => "/fapi/v1/fundingInfo",
                 Futures::Ticker24hr => "/fapi/v1/ticker/24hr",
                 Futures::TickerPrice => "/fapi/v1/ticker/price",
                 Futures::BookTicker => "/fapi/v1/ticker/bookTicker",
@@ -165,6 +288,7 @@ impl From<API> for String {
                 Futures::TopLongShortPositionRatio => "/futures/data/topLongShortPositionRatio",
                 Futures::GlobalLongShortAccountRatio => "/futures/data/globalLongShortAccountRatio",
                 Futures::TakerlongshortRatio => "/futures/data/takerlongshortRatio",
+                Futures::Basis => "/futures/data/basis",
                 Futures::LvtKlines => "/fapi/v1/lvtKlines",
                 Futures::IndexInfo => "/fapi/v1/indexInfo",
                 Futures::ChangeInitialLeverage => "/fapi/v1/leverage",
@@ -175,6 +299,36 @@ impl From<API> for String {
                 Futures::UserDataStream => "/fapi/v1/listenKey",
                 Futures::Income => "/fapi/v1/income",
             },
+            API::CoinM(route) => match route {
+                CoinM::Ping => "/dapi/v1/ping",
+                CoinM::Time => "/dapi/v1/time",
+                CoinM::ExchangeInfo => "/dapi/v1/exchangeInfo",
+                CoinM::Depth => "/dapi/v1/depth",
+                CoinM::Trades => "/dapi/v1/trades",
+                CoinM::AggTrades => "/dapi/v1/aggTrades",
+                CoinM::Klines => "/dapi/v1/klines",
+                CoinM::ContinuousKlines => "/dapi/v1/continuousKlines",
+                CoinM::IndexPriceKlines => "/dapi/v1/indexPriceKlines",
+                CoinM::MarkPriceKlines => "/dapi/v1/markPriceKlines",
+                CoinM::PremiumIndex => "/dapi/v1/premiumIndex",
+                CoinM::FundingRate => "/dapi/v1/fundingRate",
+                CoinM::Ticker24hr => "/dapi/v1/ticker/24hr",
+                CoinM::TickerPrice => "/dapi/v1/ticker/price",
+                CoinM::BookTicker => "/dapi/v1/ticker/bookTicker",
+                CoinM::OpenInterest => "/dapi/v1/openInterest",
+                CoinM::Order => "/dapi/v1/order",
+                CoinM::OpenOrders => "/dapi/v1/openOrders",
+                CoinM::AllOpenOrders => "/dapi/v1/allOpenOrders",
+                CoinM::AllOrders => "/dapi/v1/allOrders",
+                CoinM::UserTrades => "/dapi/v1/userTrades",
+                CoinM::PositionRisk => "/dapi/v1/positionRisk",
+                CoinM::Account => "/dapi/v1/account",
+                CoinM::Balance => "/dapi/v1/balance",
+                CoinM::ChangeInitialLeverage => "/dapi/v1/leverage",
+                CoinM::MarginType => "/dapi/v1/marginType",
+                CoinM::PositionMargin => "/dapi/v1/positionMargin",
+                CoinM::PositionSide => "/dapi/v1/positionSide/dual",
+            },
         })
     }
 }
@@ -188,6 +342,14 @@ pub trait Binance {
     fn set_testnet(&mut self, testnet: bool);
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        General(sync, async = "AsyncGeneral")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
 impl Binance for General {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> General {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -197,7 +359,12 @@ impl Binance for General {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> General {
         General {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
         }
     }
 
@@ -214,6 +381,14 @@ impl Binance for General {
     }
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        Account(sync, async = "AsyncAccount")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
 impl Binance for Account {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Account {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -223,7 +398,12 @@ impl Binance for Account {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Account {
         Account {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -241,6 +421,14 @@ impl Binance for Account {
     }
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        Savings(sync, async = "AsyncSavings")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
 impl Binance for Savings {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -250,7 +438,12 @@ impl Binance for Savings {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -268,6 +461,14 @@ impl Binance for Savings {
     }
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        Market(sync, async = "AsyncMarket")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
 impl Binance for Market {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Market {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -277,7 +478,12 @@ impl Binance for Market {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Market {
         Market {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -295,6 +501,14 @@ impl Binance for Market {
     }
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        UserStream(sync, async = "AsyncUserStream")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
 impl Binance for UserStream {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> UserStream {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -304,7 +518,12 @@ impl Binance for UserStream {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> UserStream {
         UserStream {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -326,6 +545,14 @@ impl Binance for UserStream {
 //              Binance Futures API
 // *****************************************************
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        FuturesGeneral(sync, async = "AsyncFuturesGeneral")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
 impl Binance for FuturesGeneral {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesGeneral {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -335,10 +562,11 @@ impl Binance for FuturesGeneral {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> FuturesGeneral {
         FuturesGeneral {
-            client: Client::new(
+            client: Client::new_with_config(
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             ),
         }
     }
@@ -356,6 +584,14 @@ impl Binance for FuturesGeneral {
     }
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        FuturesMarket(sync, async = "AsyncFuturesMarket")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
 impl Binance for FuturesMarket {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesMarket {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -365,10 +601,11 @@ impl Binance for FuturesMarket {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> FuturesMarket {
         FuturesMarket {
-            client: Client::new(
+            client: Client::new_with_config(
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             ),
             recv_window: config.recv_window,
         }
@@ -387,6 +624,14 @@ impl Binance for FuturesMarket {
     }
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        FuturesAccount(sync, async = "AsyncFuturesAccount")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
 impl Binance for FuturesAccount {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -396,10 +641,11 @@ impl Binance for FuturesAccount {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Self {
         Self {
-            client: Client::new(
+            client: Client::new_with_config(
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             ),
             recv_window: config.recv_window,
         }
@@ -418,6 +664,14 @@ impl Binance for FuturesAccount {
     }
 }
 
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        FuturesUserStream(sync, async = "AsyncFuturesUserStream")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
 impl Binance for FuturesUserStream {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesUserStream {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -427,10 +681,11 @@ impl Binance for FuturesUserStream {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> FuturesUserStream {
         FuturesUserStream {
-            client: Client::new(
+            client: Client::new_with_config(
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             ),
             recv_window: config.recv_window,
         }
@@ -448,3 +703,126 @@ impl Binance for FuturesUserStream {
         }
     }
 }
+
+// *****************************************************
+//              Binance COIN-M Futures API
+// *****************************************************
+
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        CoinMGeneral(sync, async = "AsyncCoinMGeneral")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
+impl Binance for CoinMGeneral {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.coinm_rest_api_endpoint.clone(),
+                config,
+            ),
+        }
+    }
+
+    fn set_verbose(&mut self, verbose: bool) {
+        self.client.set_verbose(verbose);
+    }
+
+    fn set_testnet(&mut self, testnet: bool) {
+        if testnet {
+            self.client.set_host(COINM_TESTNET.into());
+        } else {
+            self.client.set_host(COINM_MAINNET.into());
+        }
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        CoinMMarket(sync, async = "AsyncCoinMMarket")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
+impl Binance for CoinMMarket {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.coinm_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+
+    fn set_verbose(&mut self, verbose: bool) {
+        self.client.set_verbose(verbose);
+    }
+
+    fn set_testnet(&mut self, testnet: bool) {
+        if testnet {
+            self.client.set_host(COINM_TESTNET.into());
+        } else {
+            self.client.set_host(COINM_MAINNET.into());
+        }
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(
+        Client(sync, async = "AsyncClient"),
+        CoinMAccount(sync, async = "AsyncCoinMAccount")
+    ),
+    sync(keep_self),
+    async(feature = "async")
+)]
+impl Binance for CoinMAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new_with_config(
+                api_key,
+                secret_key,
+                config.coinm_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+
+    fn set_verbose(&mut self, verbose: bool) {
+        self.client.set_verbose(verbose);
+    }
+
+    fn set_testnet(&mut self, testnet: bool) {
+        if testnet {
+            self.client.set_host(COINM_TESTNET.into());
+        } else {
+            self.client.set_host(COINM_MAINNET.into());
+        }
+    }
+}
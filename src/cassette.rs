@@ -0,0 +1,310 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use error_chain::bail;
+use reqwest::StatusCode;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::errors::Result;
+#[cfg(feature = "async")]
+use crate::transport::{AsyncTransport, TransportFuture};
+use crate::transport::{HttpRequest, HttpResponse, Transport};
+use crate::util::is_listen_key;
+
+/// Query parameters that change on every run and are left out when matching requests.
+const VOLATILE_PARAMS: [&str; 2] = ["timestamp", "signature"];
+
+/// Stands in for the listen keys of user data streams, which change on every run.
+const LISTEN_KEY: &str = "<listenKey>";
+
+/// Prefixes of the client order ids generated by the library, followed by a random id.
+const GENERATED_ORDER_ID_PREFIXES: [&str; 2] = ["x-HNA2TXFJ", "x-Cb7ytekJ"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Go to the network and write every exchange to the cassette.
+    Record,
+    /// Serve everything from the cassette, without any network access.
+    Replay,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Entry {
+    Http {
+        method: String,
+        path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        body: Option<String>,
+        status: u16,
+        headers: Vec<(String, String)>,
+        response: String,
+    },
+    Ws {
+        stream: String,
+        frame: String,
+    },
+}
+
+/// REST responses and websocket frames recorded to a file, one JSON entry per line.
+///
+/// In `Record` mode the file is created (or truncated) and every request made through
+/// a `Config` with this cassette, and every text frame received by `WebSockets` or
+/// `FuturesWebSockets`, is appended as it happens. In `Replay` mode requests are matched
+/// on method, path, query and body, in recording order, and websocket streams play their
+/// frames back then end.
+///
+/// Hosts are not part of the match, and `timestamp`, `signature`, generated
+/// client order ids and listen keys are normalised, so a session recorded on the testnet
+/// replays against any endpoint and any key.
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    file: Mutex<Option<File>>,
+    entries: Mutex<Vec<Option<Entry>>>,
+}
+
+impl Cassette {
+    pub fn record<P: AsRef<Path>>(path: P) -> Result<Arc<Self>> {
+        let path = path.as_ref().to_path_buf();
+        let file = File::create(&path)?;
+        Ok(Arc::new(Cassette {
+            path,
+            mode: CassetteMode::Record,
+            file: Mutex::new(Some(file)),
+            entries: Mutex::new(Vec::new()),
+        }))
+    }
+
+    pub fn replay<P: AsRef<Path>>(path: P) -> Result<Arc<Self>> {
+        let path = path.as_ref().to_path_buf();
+        let mut entries = Vec::new();
+        for line in BufReader::new(File::open(&path)?).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                entries.push(Some(serde_json::from_str::<Entry>(&line)?));
+            }
+        }
+        Ok(Arc::new(Cassette {
+            path,
+            mode: CassetteMode::Replay,
+            file: Mutex::new(None),
+            entries: Mutex::new(entries),
+        }))
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn append(&self, entry: &Entry) -> Result<()> {
+        let mut line = serde_json::to_string(entry)?;
+        line.push('\n');
+        if let Some(file) = self.file.lock().unwrap().as_mut() {
+            file.write_all(line.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn record_http(&self, request: &HttpRequest, response: &HttpResponse) -> Result<()> {
+        let headers = response
+            .headers
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect();
+        self.append(&Entry::Http {
+            method: request.method.to_string(),
+            path: normalize_url(&request.url)?,
+            body: request.body.as_deref().map(normalize_query),
+            status: response.status.as_u16(),
+            headers,
+            response: String::from_utf8_lossy(&response.body).into_owned(),
+        })
+    }
+
+    pub(crate) fn replay_http(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let method = request.method.to_string();
+        let path = normalize_url(&request.url)?;
+        let body = request.body.as_deref().map(normalize_query);
+
+        let mut entries = self.entries.lock().unwrap();
+        for slot in entries.iter_mut() {
+            let found = matches!(
+                slot,
+                Some(Entry::Http { method: m, path: p, body: b, .. })
+                    if *m == method && *p == path && *b == body
+            );
+            if !found {
+                continue;
+            }
+            if let Some(Entry::Http {
+                status,
+                headers,
+                response,
+                ..
+            }) = slot.take()
+            {
+                let mut header_map = HeaderMap::new();
+                for (name, value) in headers {
+                    if let (Ok(name), Ok(value)) = (
+                        HeaderName::from_bytes(name.as_bytes()),
+                        HeaderValue::from_str(&value),
+                    ) {
+                        header_map.append(name, value);
+                    }
+                }
+                return Ok(HttpResponse {
+                    status: StatusCode::from_u16(status).unwrap_or(StatusCode::OK),
+                    headers: header_map,
+                    body: response.into_bytes(),
+                });
+            }
+        }
+        bail!(format!(
+            "No response left in cassette {} for {} {}",
+            self.path.display(),
+            method,
+            path
+        ))
+    }
+
+    pub(crate) fn record_frame(&self, stream: &str, frame: &str) -> Result<()> {
+        self.append(&Entry::Ws {
+            stream: stream.into(),
+            frame: frame.into(),
+        })
+    }
+
+    /// Takes all the frames recorded for `stream`, in order.
+    pub(crate) fn replay_frames(&self, stream: &str) -> Vec<String> {
+        let mut frames = Vec::new();
+        for slot in self.entries.lock().unwrap().iter_mut() {
+            let found = matches!(slot, Some(Entry::Ws { stream: s, .. }) if s == stream);
+            if !found {
+                continue;
+            }
+            if let Some(Entry::Ws { frame, .. }) = slot.take() {
+                frames.push(frame);
+            }
+        }
+        frames
+    }
+}
+
+impl fmt::Debug for Cassette {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Cassette")
+            .field("path", &self.path)
+            .field("mode", &self.mode)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Records what `inner` sends, or replays it without calling `inner`.
+///
+/// Set up by `Config::set_cassette`, around the configured transport.
+#[derive(Debug)]
+pub struct CassetteTransport<T> {
+    cassette: Arc<Cassette>,
+    inner: T,
+}
+
+impl<T> CassetteTransport<T> {
+    pub fn new(cassette: Arc<Cassette>, inner: T) -> Self {
+        CassetteTransport { cassette, inner }
+    }
+}
+
+impl<T: Transport> Transport for CassetteTransport<T> {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        match self.cassette.mode() {
+            CassetteMode::Replay => self.cassette.replay_http(&request),
+            CassetteMode::Record => {
+                let response = self.inner.send(request.clone())?;
+                self.cassette.record_http(&request, &response)?;
+                Ok(response)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: AsyncTransport> AsyncTransport for CassetteTransport<T> {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            match self.cassette.mode() {
+                CassetteMode::Replay => self.cassette.replay_http(&request),
+                CassetteMode::Record => {
+                    let response = self.inner.send(request.clone()).await?;
+                    self.cassette.record_http(&request, &response)?;
+                    Ok(response)
+                }
+            }
+        })
+    }
+}
+
+/// Path and normalised query of `url`, without the host.
+pub(crate) fn normalize_url(url: &str) -> Result<String> {
+    let url = Url::parse(url)?;
+    let path = normalize_streams(url.path());
+    match url.query() {
+        Some(query) => Ok(format!("{}?{}", path, normalize_query(query))),
+        None => Ok(path),
+    }
+}
+
+/// `/ws/<listenKey>`, `streams=<listenKey>/btcusdt@trade`, ...
+fn normalize_streams(streams: &str) -> String {
+    streams
+        .split('/')
+        .map(|name| {
+            if is_listen_key(name) {
+                LISTEN_KEY
+            } else {
+                name
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn normalize_query(query: &str) -> String {
+    query
+        .split('&')
+        .filter(|pair| {
+            let name = pair.split('=').next().unwrap_or_default();
+            !VOLATILE_PARAMS.contains(&name)
+        })
+        .map(|pair| match pair.split_once('=') {
+            // newClientOrderId, aboveClientOrderId, ...
+            Some((name, id)) if name.ends_with("ClientOrderId") => {
+                match GENERATED_ORDER_ID_PREFIXES
+                    .iter()
+                    .find(|prefix| id.starts_with(*prefix))
+                {
+                    Some(prefix) => format!("{}={}", name, prefix),
+                    None => pair.into(),
+                }
+            }
+            Some(("listenKey", _)) => format!("listenKey={}", LISTEN_KEY),
+            Some(("streams", streams)) => format!("streams={}", normalize_streams(streams)),
+            _ => pair.into(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
@@ -1,37 +1,85 @@
-use error_chain::bail;
-use hex::encode as hex_encode;
-use hmac::{Hmac, Mac};
-use crate::errors::{BinanceContentError, ErrorKind, Result};
-use reqwest::StatusCode;
-use reqwest::blocking::Response;
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT, CONTENT_TYPE};
-use sha2::Sha256;
+use crate::errors::{BinanceContentError, BinanceErrorCode, Error, ErrorKind, Result};
+use reqwest::{Method, StatusCode};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, RETRY_AFTER, USER_AGENT};
 use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 use crate::api::API;
+use crate::clock::{local_millis, ClockSync};
+use crate::config::Config;
+use crate::model::{RateLimit, ServerTime};
+use crate::rate_limit::{api_base_path, RateLimitUsage, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::cassette::CassetteTransport;
+use crate::signer::{HmacSigner, Signer};
+use crate::transport::{HttpRequest, HttpResponse, InvalidTransport, ReqwestTransport, Transport};
+#[cfg(feature = "async")]
+use crate::transport::{AsyncReqwestTransport, AsyncTransport};
+use tracing::Span;
+use tracing::field::Empty;
+use url::form_urlencoded::byte_serialize;
+use crate::util::{get_timestamp, redact_headers, redact_query, redact_url};
 
+// What a request has to carry: nothing, the API key header, or the API key and a signature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Security {
+    None,
+    ApiKey,
+    Signed,
+}
+
+/// HTTP client shared by every REST endpoint.
+///
+/// `Client` sends its requests through a `Transport`, `reqwest::blocking` by default.
+/// With the `async` feature enabled, `AsyncClient` is generated from the same source on
+/// top of an `AsyncTransport`, so signing, header building and response handling are
+/// identical for both.
+#[maybe_async_cfg::maybe(
+    idents(Transport(sync, async = "AsyncTransport")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncClient")
+)]
 #[derive(Clone)]
 pub struct Client {
     api_key: String,
-    secret_key: String,
+    signer: Arc<dyn Signer>,
     host: String,
-    inner_client: reqwest::blocking::Client,
+    transport: Arc<dyn Transport>,
     verbose: bool,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
+    clock_sync: Option<Arc<ClockSync>>,
 }
 
+#[maybe_async_cfg::maybe(
+    idents(Transport(sync, async = "AsyncTransport")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncClient")
+)]
 impl Client {
     pub fn new(api_key: Option<String>, secret_key: Option<String>, host: String) -> Self {
-        Client {
+        Self::new_with_config(api_key, secret_key, host, &Config::default())
+    }
+
+    pub fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, host: String, config: &Config,
+    ) -> Self {
+        Self {
             api_key: api_key.unwrap_or_default(),
-            secret_key: secret_key.unwrap_or_default(),
+            signer: match &config.signer {
+                Some(signer) => signer.clone(),
+                None => Arc::new(HmacSigner::new(secret_key.unwrap_or_default())),
+            },
             host,
-            inner_client: reqwest::blocking::Client::builder()
-                .pool_idle_timeout(None)
-                .build()
-                .unwrap(),
+            transport: Self::transport_from(config),
             verbose: false,
+            rate_limiter: config.rate_limiter.clone(),
+            retry_policy: config.retry_policy.clone(),
+            clock_sync: config.clock_sync.clone(),
         }
     }
 
+    /// Include request and response bodies in the `tracing` events.
     pub fn set_verbose(&mut self, verbose: bool) {
         self.verbose = verbose;
     }
@@ -40,181 +88,435 @@ impl Client {
         self.host = host;
     }
 
-    pub fn get_signed<T: DeserializeOwned>(
-        &self, endpoint: API, request: Option<String>,
-    ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let headers = self.build_headers(true)?;
-        if self.verbose {
-            println!("Request URL: {}", url);
-            println!("Request Headers: {:?}", headers);
-        }
-        let client = &self.inner_client;
-        let response = client.get(url.as_str()).headers(headers).send()?;
+    pub fn set_signer(&mut self, signer: Arc<dyn Signer>) {
+        self.signer = signer;
+    }
 
-        self.handler(response)
+    pub fn set_transport(&mut self, transport: Arc<dyn Transport>) {
+        self.transport = transport;
     }
 
-    pub fn post_signed<T: DeserializeOwned>(&self, endpoint: API, request: String) -> Result<T> {
-        let url = self.sign_request(endpoint, Some(request));
-        let client = &self.inner_client;
+    #[maybe_async_cfg::only_if(sync)]
+    fn transport_from(config: &Config) -> Arc<dyn Transport> {
+        let transport: Arc<dyn Transport> = match &config.transport {
+            Some(transport) => transport.clone(),
+            None => match ReqwestTransport::with_connection(&config.connection) {
+                Ok(transport) => Arc::new(transport),
+                Err(e) => Arc::new(InvalidTransport::new(e.to_string())),
+            },
+        };
+        match &config.cassette {
+            Some(cassette) => Arc::new(CassetteTransport::new(cassette.clone(), transport)),
+            None => transport,
+        }
+    }
 
-        let headers = self.build_headers(true)?;
-        if self.verbose {
-            println!("Request URL: {}", url);
-            println!("Request Headers: {:?}", headers);
+    #[maybe_async_cfg::only_if(async)]
+    fn transport_from(config: &Config) -> Arc<dyn AsyncTransport> {
+        let transport: Arc<dyn AsyncTransport> = match &config.async_transport {
+            Some(transport) => transport.clone(),
+            None => match AsyncReqwestTransport::with_connection(&config.connection) {
+                Ok(transport) => Arc::new(transport),
+                Err(e) => Arc::new(InvalidTransport::new(e.to_string())),
+            },
+        };
+        match &config.cassette {
+            Some(cassette) => Arc::new(CassetteTransport::new(cassette.clone(), transport)),
+            None => transport,
         }
-        let response = client.post(url.as_str()).headers(headers).send()?;
+    }
 
-        self.handler(response)
+    /// Request weight and order counts used on this host, as last reported by Binance,
+    /// for each API base path.
+    pub fn rate_limit_usage(&self) -> Vec<RateLimitUsage> {
+        self.rate_limiter.usage(&self.host)
     }
 
-    pub fn delete_signed<T: DeserializeOwned>(
+    /// Set the limits enforced by the rate limiter on the API `endpoint` belongs to, see
+    /// `Config::set_rate_limit_policy`.
+    pub fn set_rate_limits(&self, endpoint: API, limits: &[RateLimit]) {
+        let path = String::from(endpoint);
+        self.rate_limiter
+            .set_limits(&self.host, api_base_path(&path), limits);
+    }
+
+    pub async fn get_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let headers = self.build_headers(true)?;
-        if self.verbose {
-            println!("Request URL: {}", url);
-            println!("Request Headers: {:?}", headers);
-        }
-        let client = &self.inner_client;
-        let response = client.delete(url.as_str()).headers(headers).send()?;
+        self.request(Method::GET, endpoint, request, Security::Signed, None)
+            .await
+    }
 
-        self.handler(response)
+    pub async fn post_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.request(
+            Method::POST,
+            endpoint,
+            Some(request),
+            Security::Signed,
+            None,
+        )
+        .await
     }
 
-    pub fn get<T: DeserializeOwned>(&self, endpoint: API, request: Option<String>) -> Result<T> {
-        let mut url: String = format!("{}{}", self.host, String::from(endpoint));
-        if let Some(request) = request {
-            if !request.is_empty() {
-                url.push_str(format!("?{}", request).as_str());
-            }
-        }
+    pub async fn delete_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.request(Method::DELETE, endpoint, request, Security::Signed, None)
+            .await
+    }
 
-        let client = &self.inner_client;
-        if self.verbose {
-            println!("Request URL: {}", url);
-        }
-        let response = client.get(url.as_str()).send()?;
+    pub async fn put_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.request(Method::PUT, endpoint, Some(request), Security::Signed, None)
+            .await
+    }
 
-        self.handler(response)
+    pub async fn get<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.request(Method::GET, endpoint, request, Security::None, None)
+            .await
     }
 
-    pub fn post<T: DeserializeOwned>(&self, endpoint: API) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
+    // Unsigned GET that still needs the API key header (MARKET_DATA endpoints)
+    pub async fn get_with_api_key<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.request(Method::GET, endpoint, request, Security::ApiKey, None)
+            .await
+    }
 
-        let client = &self.inner_client;
-        let response = client
-            .post(url.as_str())
-            .headers(self.build_headers(false)?)
-            .send()?;
+    pub async fn post<T: DeserializeOwned>(&self, endpoint: API) -> Result<T> {
+        self.request(Method::POST, endpoint, None, Security::None, None)
+            .await
+    }
 
-        self.handler(response)
+    pub async fn put<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+        let data: String = format!("listenKey={}", listen_key);
+        self.request(Method::PUT, endpoint, None, Security::None, Some(data))
+            .await
     }
 
-    pub fn put<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
+    pub async fn delete<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
         let data: String = format!("listenKey={}", listen_key);
+        self.request(Method::DELETE, endpoint, None, Security::None, Some(data))
+            .await
+    }
 
-        let client = &self.inner_client;
+    /// Measure the offset between the local clock and the server time returned by
+    /// `endpoint`, in milliseconds. It is applied to signed requests when clock sync
+    /// is enabled, see `Config::set_clock_sync`.
+    pub async fn sync_clock(&self, endpoint: API) -> Result<i64> {
+        self.sync_clock_with(&String::from(endpoint)).await
+    }
+
+    async fn sync_clock_with(&self, path: &str) -> Result<i64> {
+        let sent_at = local_millis();
+        let server_time: ServerTime = self
+            .send(Method::GET, path, None, Security::None, None, false)
+            .await?;
+        let received_at = local_millis();
 
-        let headers = self.build_headers(true)?;
-        if self.verbose {
-            println!("Request URL: {}", url);
-            println!("Request Headers: {:?}", headers);
-            println!("Request Body: {}", data);
+        // the server time is taken somewhere around the middle of the round trip
+        let offset = server_time.server_time as i64 - (sent_at + received_at) / 2;
+        tracing::debug!(offset_ms = offset, "server time offset measured");
+        if let Some(clock_sync) = &self.clock_sync {
+            clock_sync.set_offset(&self.host, offset);
         }
-        let response = client
-            .put(url.as_str())
-            .headers(headers)
-            .body(data)
-            .send()?;
+        Ok(offset)
+    }
 
-        self.handler(response)
+    async fn request<T: DeserializeOwned>(
+        &self, method: Method, endpoint: API, request: Option<String>, security: Security,
+        body: Option<String>,
+    ) -> Result<T> {
+        let places_order = method == Method::POST && endpoint.places_order();
+        let time_path = String::from(endpoint.time_endpoint());
+        let path = String::from(endpoint);
+
+        let clock_sync = match &self.clock_sync {
+            Some(clock_sync) if security == Security::Signed => clock_sync,
+            _ => {
+                return self
+                    .send(method, &path, request, security, body, places_order)
+                    .await;
+            }
+        };
+
+        if clock_sync.is_stale(&self.host) {
+            self.sync_clock_with(&time_path).await?;
+        }
+        let result = self
+            .send(
+                method.clone(),
+                &path,
+                request.clone(),
+                security,
+                body.clone(),
+                places_order,
+            )
+            .await;
+        match result {
+            // -1021 Timestamp for this request is outside of the recvWindow: the request
+            // was rejected, so it is safe to send it again once the clock is synced
+            Err(Error(ErrorKind::BinanceError(ref content), _))
+                if content.error_code() == BinanceErrorCode::InvalidTimestamp =>
+            {
+                self.sync_clock_with(&time_path).await?;
+                self.send(method, &path, request, security, body, places_order)
+                    .await
+            }
+            result => result,
+        }
     }
 
-    pub fn delete<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
-        let data: String = format!("listenKey={}", listen_key);
+    async fn send<T: DeserializeOwned>(
+        &self, method: Method, path: &str, mut request: Option<String>, security: Security,
+        body: Option<String>, places_order: bool,
+    ) -> Result<T> {
+        let signed = security == Security::Signed;
+        // signed POSTs place orders, move funds, ... so they must not be sent twice blindly
+        let idempotent = !(signed && method == Method::POST);
 
-        let client = &self.inner_client;
-        let response = client
-            .delete(url.as_str())
-            .headers(self.build_headers(false)?)
-            .body(data)
-            .send()?;
+        let span = tracing::debug_span!(
+            "binance_request",
+            method = %method,
+            endpoint = path,
+            status = Empty,
+            error_code = Empty,
+            used_weight = Empty,
+            latency_ms = Empty,
+            attempts = Empty,
+        );
+        let started_at = Instant::now();
 
-        self.handler(response)
+        let mut attempt = 1;
+        loop {
+            span.record("attempts", attempt);
+            let waited = self
+                .wait_for_rate_limit(&span, api_base_path(path), places_order)
+                .await?;
+            if signed {
+                if let Some(clock_sync) = &self.clock_sync {
+                    request = request
+                        .as_deref()
+                        .map(|r| refresh_timestamp(r, clock_sync.timestamp(&self.host)));
+                } else if waited || attempt > 1 {
+                    // the timestamp was taken before waiting, take it again so the request
+                    // still falls within its recvWindow
+                    let now = get_timestamp(SystemTime::now())?;
+                    request = request.as_deref().map(|r| refresh_timestamp(r, now));
+                }
+            }
+
+            let url = if signed {
+                self.sign_request(path, request.clone())
+            } else {
+                let mut url: String = format!("{}{}", self.host, path);
+                if let Some(request) = &request {
+                    if !request.is_empty() {
+                        url.push_str(format!("?{}", request).as_str());
+                    }
+                }
+                url
+            };
+
+            // plain GETs go out without any custom header
+            let mut headers = HeaderMap::new();
+            if security != Security::None || method != Method::GET {
+                headers = self.build_headers(signed || body.is_some())?;
+            }
+            tracing::trace!(
+                parent: &span,
+                url = %redact_url(&url),
+                headers = ?redact_headers(&headers),
+                body = self.verbose.then(|| body.as_deref().map(redact_query)).flatten(),
+                "sending request",
+            );
+            let http_request = HttpRequest {
+                method: method.clone(),
+                url,
+                headers,
+                body: body.clone(),
+            };
+
+            let result = self.transport.send(http_request).await;
+            span.record("latency_ms", started_at.elapsed().as_millis() as u64);
+            let delay = match result {
+                Ok(response) => {
+                    span.record("status", response.status.as_u16());
+                    if let Some(weight) = used_weight(&response.headers) {
+                        span.record("used_weight", weight);
+                    }
+                    self.rate_limiter.update_from_headers(
+                        &self.host,
+                        api_base_path(path),
+                        &response.headers,
+                    );
+                    let retry_after = retry_after(&response.headers);
+                    let delay = self.retry_policy.retry_status(
+                        attempt,
+                        response.status,
+                        retry_after,
+                        idempotent,
+                    );
+                    if let Some(delay) = delay {
+                        delay
+                    } else {
+                        return self.handler(&span, response, path);
+                    }
+                }
+                Err(error) => {
+                    let delay = self.retry_policy.retry_error(attempt, &error, idempotent);
+                    if let Some(delay) = delay {
+                        tracing::debug!(parent: &span, error = %error, "request failed");
+                        delay
+                    } else {
+                        tracing::warn!(parent: &span, error = %error, "request failed");
+                        return Err(error);
+                    }
+                }
+            };
+
+            tracing::info!(parent: &span, attempt, delay_ms = delay.as_millis() as u64, "retrying request");
+            self.sleep(delay).await;
+            attempt += 1;
+        }
     }
 
-    // Request must be signed
-    fn sign_request(&self, endpoint: API, request: Option<String>) -> String {
-        if let Some(request) = request {
-            let mut signed_key =
-                Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            signed_key.update(request.as_bytes());
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("{}&signature={}", request, signature);
-            format!("{}{}?{}", self.host, String::from(endpoint), request_body)
-        } else {
-            let signed_key = Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("&signature={}", signature);
-            format!("{}{}?{}", self.host, String::from(endpoint), request_body)
+    /// Holds the request back while a limit is reached, returns whether it had to wait.
+    async fn wait_for_rate_limit(
+        &self, span: &Span, api: &str, places_order: bool,
+    ) -> Result<bool> {
+        match self.rate_limiter.check(&self.host, api, places_order)? {
+            Some(wait) => {
+                tracing::info!(
+                    parent: span,
+                    wait_ms = wait.as_millis() as u64,
+                    "rate limit reached, waiting"
+                );
+                self.sleep(wait).await;
+                Ok(true)
+            }
+            None => Ok(false),
         }
     }
 
-    fn build_headers(&self, content_type: bool) -> Result<HeaderMap> {
-        let mut custom_headers = HeaderMap::new();
+    #[maybe_async_cfg::only_if(sync)]
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
 
-        custom_headers.insert(USER_AGENT, HeaderValue::from_static("binance-rs"));
-        if content_type {
-            custom_headers.insert(
-                CONTENT_TYPE,
-                HeaderValue::from_static("application/x-www-form-urlencoded"),
-            );
-        }
-        custom_headers.insert(
-            HeaderName::from_static("x-mbx-apikey"),
-            HeaderValue::from_str(self.api_key.as_str())?,
-        );
+    #[maybe_async_cfg::only_if(async)]
+    async fn sleep(&self, duration: Duration) {
+        tokio::time::sleep(duration).await;
+    }
 
-        Ok(custom_headers)
+    // Request must be signed
+    fn sign_request(&self, path: &str, request: Option<String>) -> String {
+        sign_request(&self.host, self.signer.as_ref(), path, request)
     }
 
-    fn handler<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
-        match response.status() {
+    fn build_headers(&self, content_type: bool) -> Result<HeaderMap> {
+        build_headers(&self.api_key, content_type)
+    }
+
+    fn handler<T: DeserializeOwned>(
+        &self, span: &Span, response: HttpResponse, path: &str,
+    ) -> Result<T> {
+        match response.status {
             StatusCode::OK => {
-                let headers = response.headers().clone();
-                let response_bytes = response.bytes()?;
-
-                if self.verbose {
-                    println!("Response Headers: {:?}", headers);
-                    let pretty =
-                        serde_json::from_slice::<serde_json::Value>(&response_bytes).unwrap();
-                    println!("Response: {}", pretty);
+                tracing::debug!(
+                    parent: span,
+                    body = self
+                        .verbose
+                        .then(|| String::from_utf8_lossy(&response.body).into_owned()),
+                    "request succeeded",
+                );
+                match serde_json::from_slice(&response.body) {
+                    Ok(json) => Ok(json),
+                    Err(error) => {
+                        tracing::warn!(parent: span, error = %error, "invalid response");
+                        Err(error.into())
+                    }
                 }
-                let json: T = serde_json::from_slice(&response_bytes)?;
-                Ok(json)
-            }
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                bail!("Internal Server Error");
             }
-            StatusCode::SERVICE_UNAVAILABLE => {
-                bail!("Service Unavailable");
-            }
-            StatusCode::UNAUTHORIZED => {
-                bail!("Unauthorized");
-            }
-            StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json()?;
+            status => {
+                let mut error = serde_json::from_slice::<BinanceContentError>(&response.body)
+                    .unwrap_or_else(|_| BinanceContentError {
+                        code: 0,
+                        msg: status.canonical_reason().unwrap_or("Unknown").into(),
+                        status: 0,
+                        endpoint: String::new(),
+                        data: None,
+                    });
+                error.status = status.as_u16();
+                error.endpoint = path.into();
+                span.record("error_code", error.code);
+                tracing::warn!(parent: span, code = error.code, msg = %error.msg, "request rejected");
 
                 Err(ErrorKind::BinanceError(error).into())
             }
-            s => {
-                bail!(format!("Received response: {:?}", s));
-            }
         }
     }
 }
+
+fn sign_request(host: &str, signer: &dyn Signer, path: &str, request: Option<String>) -> String {
+    let request = request.unwrap_or_default();
+    // RSA and Ed25519 signatures are base64, which has to be escaped
+    let signature: String = byte_serialize(signer.sign(&request).as_bytes()).collect();
+    let request_body: String = format!("{}&signature={}", request, signature);
+    format!("{}{}?{}", host, path, request_body)
+}
+
+fn refresh_timestamp(request: &str, timestamp: u64) -> String {
+    request
+        .split('&')
+        .map(|param| {
+            if param.starts_with("timestamp=") {
+                format!("timestamp={}", timestamp)
+            } else {
+                param.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+// request weight used in the current minute, as reported by Binance
+fn used_weight(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get("x-mbx-used-weight-1m")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+}
+
+// `Retry-After` is sent in seconds with 429 and 418
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+fn build_headers(api_key: &str, content_type: bool) -> Result<HeaderMap> {
+    let mut custom_headers = HeaderMap::new();
+
+    custom_headers.insert(USER_AGENT, HeaderValue::from_static("binance-rs"));
+    if content_type {
+        custom_headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+    }
+    custom_headers.insert(
+        HeaderName::from_static("x-mbx-apikey"),
+        HeaderValue::from_str(api_key)?,
+    );
+
+    Ok(custom_headers)
+}
@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Offsets between the local clock and the Binance server time, one per host.
+///
+/// When enabled with `Config::set_clock_sync`, the offset of a host is measured with
+/// its server time endpoint before the first signed request, measured again every
+/// `refresh_interval` and after a `-1021 Timestamp outside recvWindow` error, and
+/// the `timestamp` of every signed request is taken from `ClockSync::timestamp`.
+/// Spot and futures clients built from the same `Config` keep separate offsets.
+#[derive(Debug)]
+pub struct ClockSync {
+    refresh_interval: Duration,
+    offsets: Mutex<BTreeMap<String, Synced>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Synced {
+    offset: i64,
+    synced_at: Instant,
+}
+
+impl ClockSync {
+    pub fn new(refresh_interval: Duration) -> Self {
+        ClockSync {
+            refresh_interval,
+            offsets: Mutex::default(),
+        }
+    }
+
+    /// Milliseconds to add to the local clock to get the server time of `host`.
+    pub fn offset(&self, host: &str) -> i64 {
+        self.offsets()
+            .get(host)
+            .map(|synced| synced.offset)
+            .unwrap_or_default()
+    }
+
+    pub fn set_offset(&self, host: &str, offset: i64) {
+        self.offsets().insert(
+            host.into(),
+            Synced {
+                offset,
+                synced_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Whether the offset of `host` was never measured or is older than the refresh
+    /// interval.
+    pub fn is_stale(&self, host: &str) -> bool {
+        match self.offsets().get(host) {
+            Some(synced) => synced.synced_at.elapsed() >= self.refresh_interval,
+            None => true,
+        }
+    }
+
+    /// Current server time of `host` in milliseconds, as estimated from the local clock.
+    pub fn timestamp(&self, host: &str) -> u64 {
+        (local_millis() + self.offset(host)).max(0) as u64
+    }
+
+    fn offsets(&self) -> MutexGuard<'_, BTreeMap<String, Synced>> {
+        self.offsets.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+pub(crate) fn local_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
@@ -1,3 +1,16 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::cassette::Cassette;
+use crate::clock::ClockSync;
+use crate::rate_limit::{RateLimitPolicy, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::signer::Signer;
+#[cfg(feature = "async")]
+use crate::transport::AsyncTransport;
+use crate::transport::Transport;
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub rest_api_endpoint: String,
@@ -6,7 +19,42 @@ pub struct Config {
     pub futures_rest_api_endpoint: String,
     pub futures_ws_endpoint: String,
 
+    pub coinm_rest_api_endpoint: String,
+
     pub recv_window: u64,
+
+    /// Timeouts, proxy and bind address of the REST and websocket connections.
+    pub connection: ConnectionConfig,
+
+    /// Shared by every client built from this config (and its clones).
+    pub rate_limiter: Arc<RateLimiter>,
+    pub retry_policy: RetryPolicy,
+
+    /// Off by default, signed requests are then stamped with the local clock.
+    pub clock_sync: Option<Arc<ClockSync>>,
+
+    /// Signs `SIGNED` requests, HMAC with the secret key when not set.
+    pub signer: Option<Arc<dyn Signer>>,
+
+    /// Sends the requests, `ReqwestTransport` when not set.
+    pub transport: Option<Arc<dyn Transport>>,
+    #[cfg(feature = "async")]
+    pub async_transport: Option<Arc<dyn AsyncTransport>>,
+
+    /// Records or replays REST and websocket traffic.
+    pub cassette: Option<Arc<Cassette>>,
+}
+
+/// How connections to Binance are opened, for REST clients and websockets alike.
+#[derive(Clone, Debug, Default)]
+pub struct ConnectionConfig {
+    pub connect_timeout: Option<Duration>,
+    /// Whole request for REST (30s when not set), time without any frame for websockets.
+    pub timeout: Option<Duration>,
+    /// `http://`, `socks5://` or `socks5h://` URL, with optional `user:password@`.
+    pub proxy: Option<String>,
+    /// Local address to connect from.
+    pub local_address: Option<IpAddr>,
 }
 
 pub const SPOT_MAINNET: &str = "https://api.binance.com";
@@ -18,6 +66,9 @@ pub const SPOT_WS_TESTNET: &str = "wss://testnet.binance.vision/ws";
 pub const FUTURES_MAINNET: &str = "https://fapi.binance.com";
 pub const FUTURES_TESTNET: &str = "https://testnet.binancefuture.com";
 
+pub const COINM_MAINNET: &str = "https://dapi.binance.com";
+pub const COINM_TESTNET: &str = "https://testnet.binancefuture.com";
+
 pub const FUTURES_WS_MAINNET: &str = "wss://fstream.binance.com/ws";
 pub const FUTURES_WS_TESTNET: &str = "wss://fstream.binancefuture.com/ws";
 
@@ -30,7 +81,24 @@ impl Default for Config {
             futures_rest_api_endpoint: FUTURES_MAINNET.into(),
             futures_ws_endpoint: FUTURES_WS_MAINNET.into(),
 
+            coinm_rest_api_endpoint: COINM_MAINNET.into(),
+
             recv_window: 5000,
+
+            connection: ConnectionConfig::default(),
+
+            rate_limiter: Arc::default(),
+            retry_policy: RetryPolicy::default(),
+
+            clock_sync: None,
+
+            signer: None,
+
+            transport: None,
+            #[cfg(feature = "async")]
+            async_transport: None,
+
+            cassette: None,
         }
     }
 }
@@ -42,6 +110,7 @@ impl Config {
             .set_ws_endpoint(SPOT_WS_TESTNET)
             .set_futures_rest_api_endpoint(FUTURES_TESTNET)
             .set_futures_ws_endpoint(FUTURES_WS_TESTNET)
+            .set_coinm_rest_api_endpoint(COINM_TESTNET)
     }
 
     pub fn set_rest_api_endpoint<T: Into<String>>(mut self, rest_api_endpoint: T) -> Self {
@@ -65,8 +134,81 @@ impl Config {
         self
     }
 
+    pub fn set_coinm_rest_api_endpoint<T: Into<String>>(
+        mut self, coinm_rest_api_endpoint: T,
+    ) -> Self {
+        self.coinm_rest_api_endpoint = coinm_rest_api_endpoint.into();
+        self
+    }
+
     pub fn set_recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = recv_window;
         self
     }
+
+    pub fn set_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connection.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Timeout of REST requests. Websockets return an error from `event_loop` when
+    /// nothing is received for that long.
+    pub fn set_timeout(mut self, timeout: Duration) -> Self {
+        self.connection.timeout = Some(timeout);
+        self
+    }
+
+    /// Go through an HTTP (`http://host:port`) or SOCKS5 (`socks5://host:port`) proxy.
+    /// If the URL is invalid, the REST requests fail with the reason.
+    pub fn set_proxy<T: Into<String>>(mut self, proxy: T) -> Self {
+        self.connection.proxy = Some(proxy.into());
+        self
+    }
+
+    pub fn set_local_address(mut self, local_address: IpAddr) -> Self {
+        self.connection.local_address = Some(local_address);
+        self
+    }
+
+    pub fn set_rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+        self.rate_limiter = Arc::new(RateLimiter::new(policy));
+        self
+    }
+
+    pub fn set_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Stamp signed requests with the server time, measuring the clock offset again
+    /// every `refresh_interval`. The offset is kept per host, so spot and futures
+    /// clients built from this `Config` each use their own server's time.
+    pub fn set_clock_sync(mut self, refresh_interval: Duration) -> Self {
+        self.clock_sync = Some(Arc::new(ClockSync::new(refresh_interval)));
+        self
+    }
+
+    /// Sign requests with `signer` (e.g. an `Ed25519Signer`) instead of HMAC.
+    pub fn set_signer<S: Signer + 'static>(mut self, signer: S) -> Self {
+        self.signer = Some(Arc::new(signer));
+        self
+    }
+
+    pub fn set_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    #[cfg(feature = "async")]
+    pub fn set_async_transport<T: AsyncTransport + 'static>(mut self, transport: T) -> Self {
+        self.async_transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Record the traffic of the clients and websockets built from this config to
+    /// `cassette`, or replay it from there, see `Cassette`.
+    pub fn set_cassette(mut self, cassette: Arc<Cassette>) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
 }
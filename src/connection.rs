@@ -0,0 +1,198 @@
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use error_chain::bail;
+use percent_encoding::percent_decode_str;
+use socket2::{Domain, Protocol, Socket, Type};
+use tungstenite::client_tls_with_config;
+use tungstenite::handshake::client::Response;
+use tungstenite::protocol::WebSocket;
+use tungstenite::stream::MaybeTlsStream;
+use url::Url;
+
+use crate::config::ConnectionConfig;
+use crate::errors::Result;
+
+pub(crate) type WebSocketConnection = (WebSocket<MaybeTlsStream<TcpStream>>, Response);
+
+// longest proxy response header accepted
+const MAX_PROXY_RESPONSE: usize = 8192;
+
+/// Opens the websocket at `url`, through the proxy if one is set.
+pub(crate) fn connect_websocket(
+    url: &Url, connection: &ConnectionConfig,
+) -> Result<WebSocketConnection> {
+    let host = match url.host_str() {
+        Some(host) => host,
+        None => bail!(format!("Missing host in {}", url)),
+    };
+    let port = url.port_or_known_default().unwrap_or(443);
+
+    let stream = match &connection.proxy {
+        None => tcp_connect(host, port, connection)?,
+        Some(proxy) => {
+            let proxy = Url::parse(proxy)?;
+            let proxy_host = match proxy.host_str() {
+                Some(proxy_host) => proxy_host,
+                None => bail!(format!("Missing host in proxy {}", proxy)),
+            };
+            let default_port = if proxy.scheme() == "http" { 80 } else { 1080 };
+            let mut stream =
+                tcp_connect(proxy_host, proxy.port().unwrap_or(default_port), connection)?;
+            match proxy.scheme() {
+                "http" => http_connect(&mut stream, host, port, &proxy)?,
+                "socks5" | "socks5h" => socks5_connect(&mut stream, host, port, &proxy)?,
+                scheme => bail!(format!("Unsupported proxy scheme {}", scheme)),
+            }
+            stream
+        }
+    };
+    stream.set_read_timeout(connection.timeout)?;
+
+    match client_tls_with_config(url.as_str(), stream, None, None) {
+        Ok(socket) => Ok(socket),
+        Err(e) => bail!(format!("{}", e)),
+    }
+}
+
+fn tcp_connect(host: &str, port: u16, connection: &ConnectionConfig) -> Result<TcpStream> {
+    let mut last_error = None;
+    for address in (host, port).to_socket_addrs()? {
+        // a local IPv4 address can't reach an IPv6 one and vice versa
+        if let Some(local_address) = connection.local_address {
+            if local_address.is_ipv4() != address.is_ipv4() {
+                continue;
+            }
+        }
+        match connect_address(address, connection) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    match last_error {
+        Some(e) => Err(e.into()),
+        None => bail!(format!("No usable address for {}:{}", host, port)),
+    }
+}
+
+fn connect_address(address: SocketAddr, connection: &ConnectionConfig) -> io::Result<TcpStream> {
+    let socket = Socket::new(
+        Domain::for_address(address),
+        Type::STREAM,
+        Some(Protocol::TCP),
+    )?;
+    if let Some(local_address) = connection.local_address {
+        socket.bind(&SocketAddr::new(local_address, 0).into())?;
+    }
+    match connection.connect_timeout {
+        Some(timeout) => socket.connect_timeout(&address.into(), timeout)?,
+        None => socket.connect(&address.into())?,
+    }
+    socket.set_nodelay(true)?;
+    Ok(socket.into())
+}
+
+fn credentials(proxy: &Url) -> Option<(String, String)> {
+    if proxy.username().is_empty() {
+        return None;
+    }
+    let decode = |s: &str| percent_decode_str(s).decode_utf8_lossy().into_owned();
+    Some((
+        decode(proxy.username()),
+        decode(proxy.password().unwrap_or_default()),
+    ))
+}
+
+// HTTP CONNECT tunnel, see RFC 9110 section 9.3.6
+fn http_connect(stream: &mut TcpStream, host: &str, port: u16, proxy: &Url) -> Result<()> {
+    let mut request = format!(
+        "CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n",
+        host = host,
+        port = port
+    );
+    if let Some((username, password)) = credentials(proxy) {
+        let token = BASE64.encode(format!("{}:{}", username, password));
+        request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", token));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes())?;
+
+    // read byte by byte so nothing of the TLS handshake that follows is consumed
+    let mut response = Vec::new();
+    let mut byte = [0u8; 1];
+    while !response.ends_with(b"\r\n\r\n") {
+        if stream.read(&mut byte)? == 0 || response.len() > MAX_PROXY_RESPONSE {
+            bail!("Invalid response from the proxy");
+        }
+        response.push(byte[0]);
+    }
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    if status_line.split_whitespace().nth(1) != Some("200") {
+        bail!(format!("Proxy refused the connection: {}", status_line));
+    }
+    Ok(())
+}
+
+// SOCKS5 CONNECT, see RFC 1928 and RFC 1929 for the authentication
+fn socks5_connect(stream: &mut TcpStream, host: &str, port: u16, proxy: &Url) -> Result<()> {
+    let credentials = credentials(proxy);
+    match credentials {
+        Some(_) => stream.write_all(&[5, 2, 0, 2])?,
+        None => stream.write_all(&[5, 1, 0])?,
+    }
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply)?;
+    match (reply, &credentials) {
+        ([5, 0], _) => (),
+        ([5, 2], Some((username, password))) => {
+            if username.len() > 255 || password.len() > 255 {
+                bail!("SOCKS5 username and password are limited to 255 bytes");
+            }
+            let mut request = vec![1, username.len() as u8];
+            request.extend_from_slice(username.as_bytes());
+            request.push(password.len() as u8);
+            request.extend_from_slice(password.as_bytes());
+            stream.write_all(&request)?;
+            stream.read_exact(&mut reply)?;
+            if reply[1] != 0 {
+                bail!("SOCKS5 proxy rejected the credentials");
+            }
+        }
+        _ => bail!("SOCKS5 proxy doesn't accept any offered authentication method"),
+    }
+
+    // the proxy resolves the host name
+    if host.len() > 255 {
+        bail!(format!("Host name too long for SOCKS5: {}", host));
+    }
+    let mut request = vec![5, 1, 0, 3, host.len() as u8];
+    request.extend_from_slice(host.as_bytes());
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request)?;
+
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header)?;
+    if header[1] != 0 {
+        bail!(format!(
+            "SOCKS5 proxy refused the connection (reply {})",
+            header[1]
+        ));
+    }
+    // bound address and port, unused
+    let address_len = match header[3] {
+        1 => 4,
+        4 => 16,
+        3 => {
+            let mut len = [0u8; 1];
+            stream.read_exact(&mut len)?;
+            len[0] as usize
+        }
+        atyp => bail!(format!("Invalid SOCKS5 address type {}", atyp)),
+    };
+    let mut bound = vec![0u8; address_len + 2];
+    stream.read_exact(&mut bound)?;
+    Ok(())
+}
@@ -1,20 +1,223 @@
+use std::fmt;
+
 use serde::Deserialize;
 use error_chain::error_chain;
+use crate::model::OrderCancelReplace;
+use crate::rate_limit::RateLimitUsage;
+use crate::validator::FilterViolation;
 
+/// Error returned by Binance for any response other than 200.
+///
+/// `code` and `msg` come from the JSON body. When the body isn't JSON (e.g. a 503
+/// from the gateway), `code` is 0 and `msg` is the HTTP reason.
 #[derive(Debug, Deserialize)]
 pub struct BinanceContentError {
     pub code: i16,
     pub msg: String,
+
+    /// HTTP status of the response.
+    #[serde(skip)]
+    pub status: u16,
+    /// Path of the endpoint that was called, e.g. `/api/v3/order`.
+    #[serde(skip)]
+    pub endpoint: String,
+    /// Details sent along with some errors, e.g. the outcome of both halves of a
+    /// failed cancel-replace.
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+}
+
+impl BinanceContentError {
+    /// The code, read with the futures meanings when `endpoint` is a futures one.
+    pub fn error_code(&self) -> BinanceErrorCode {
+        if self.endpoint.starts_with("/fapi/") || self.endpoint.starts_with("/dapi/") {
+            BinanceErrorCode::from_futures(self.code)
+        } else {
+            BinanceErrorCode::from(self.code)
+        }
+    }
+}
+
+impl fmt::Display for BinanceContentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {} (code {})",
+            self.status, self.endpoint, self.msg, self.code
+        )
+    }
+}
+
+/// Binance error codes, see
+/// <https://developers.binance.com/docs/binance-spot-api-docs/errors> and the
+/// futures error codes for the -4xxx range.
+///
+/// -2021 and -2022 mean different things on the spot and futures APIs: `from` gives the
+/// spot meaning, `from_futures` the futures one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinanceErrorCode {
+    /// -1000
+    UnknownError,
+    /// -1001, internal error; unable to process your request
+    Disconnected,
+    /// -1002
+    Unauthorized,
+    /// -1003, too much request weight used or too many requests queued
+    TooManyRequests,
+    /// -1006
+    UnexpectedResponse,
+    /// -1007, backend timeout; the execution status is unknown
+    Timeout,
+    /// -1008, server is currently overloaded
+    ServerBusy,
+    /// -1013, a filter (e.g. LOT_SIZE) rejected the request
+    FilterFailure,
+    /// -1014
+    UnknownOrderComposition,
+    /// -1015, too many new orders
+    TooManyOrders,
+    /// -1016
+    ServiceShuttingDown,
+    /// -1020
+    UnsupportedOperation,
+    /// -1021, timestamp outside of the recvWindow
+    InvalidTimestamp,
+    /// -1022
+    InvalidSignature,
+    /// -1100
+    IllegalChars,
+    /// -1101
+    TooManyParameters,
+    /// -1102
+    MandatoryParamEmptyOrMalformed,
+    /// -1103
+    UnknownParam,
+    /// -1104
+    UnreadParameters,
+    /// -1105
+    ParamEmpty,
+    /// -1106
+    ParamNotRequired,
+    /// -1111
+    BadPrecision,
+    /// -1115
+    InvalidTimeInForce,
+    /// -1116
+    InvalidOrderType,
+    /// -1117
+    InvalidSide,
+    /// -1121
+    BadSymbol,
+    /// -1125
+    InvalidListenKey,
+    /// -2010
+    NewOrderRejected,
+    /// -2011
+    CancelRejected,
+    /// -2013
+    NoSuchOrder,
+    /// -2014
+    BadApiKeyFormat,
+    /// -2015, invalid API key, IP or permissions for action
+    RejectedMbxKey,
+    /// -2019
+    MarginNotSufficient,
+    /// -2021 on spot, either the cancel or the new order of a cancel-replace failed
+    CancelReplacePartiallyFailed,
+    /// -2022 on spot, both the cancel and the new order of a cancel-replace failed
+    CancelReplaceFailed,
+    /// -2021 on futures, the stop price would trigger the order immediately
+    OrderWouldImmediatelyTrigger,
+    /// -2022 on futures, the reduce-only order was rejected
+    ReduceOnlyRejected,
+    /// -4164, order notional below the minimum
+    MinNotional,
+    /// Any other code, or 0 when the response carried no Binance error.
+    Other(i16),
+}
+
+impl From<i16> for BinanceErrorCode {
+    fn from(code: i16) -> Self {
+        match code {
+            -1000 => Self::UnknownError,
+            -1001 => Self::Disconnected,
+            -1002 => Self::Unauthorized,
+            -1003 => Self::TooManyRequests,
+            -1006 => Self::UnexpectedResponse,
+            -1007 => Self::Timeout,
+            -1008 => Self::ServerBusy,
+            -1013 => Self::FilterFailure,
+            -1014 => Self::UnknownOrderComposition,
+            -1015 => Self::TooManyOrders,
+            -1016 => Self::ServiceShuttingDown,
+            -1020 => Self::UnsupportedOperation,
+            -1021 => Self::InvalidTimestamp,
+            -1022 => Self::InvalidSignature,
+            -1100 => Self::IllegalChars,
+            -1101 => Self::TooManyParameters,
+            -1102 => Self::MandatoryParamEmptyOrMalformed,
+            -1103 => Self::UnknownParam,
+            -1104 => Self::UnreadParameters,
+            -1105 => Self::ParamEmpty,
+            -1106 => Self::ParamNotRequired,
+            -1111 => Self::BadPrecision,
+            -1115 => Self::InvalidTimeInForce,
+            -1116 => Self::InvalidOrderType,
+            -1117 => Self::InvalidSide,
+            -1121 => Self::BadSymbol,
+            -1125 => Self::InvalidListenKey,
+            -2010 => Self::NewOrderRejected,
+            -2011 => Self::CancelRejected,
+            -2013 => Self::NoSuchOrder,
+            -2014 => Self::BadApiKeyFormat,
+            -2015 => Self::RejectedMbxKey,
+            -2019 => Self::MarginNotSufficient,
+            -2021 => Self::CancelReplacePartiallyFailed,
+            -2022 => Self::CancelReplaceFailed,
+            -4164 => Self::MinNotional,
+            code => Self::Other(code),
+        }
+    }
+}
+
+impl BinanceErrorCode {
+    /// `code` as returned by the USD-M or COIN-M futures API.
+    pub fn from_futures(code: i16) -> Self {
+        match code {
+            -2021 => Self::OrderWouldImmediatelyTrigger,
+            -2022 => Self::ReduceOnlyRejected,
+            code => Self::from(code),
+        }
+    }
 }
 
 error_chain! {
     errors {
-        BinanceError(response: BinanceContentError)
+        BinanceError(response: BinanceContentError) {
+            description("error returned by Binance"),
+            display("{}", response),
+        }
 
         KlineValueMissingError(index: usize, name: &'static str) {
             description("invalid Vec for Kline"),
             display("{} at {} is missing", name, index),
         }
+
+        RateLimitExceeded(usage: RateLimitUsage, retry_after: std::time::Duration) {
+            description("local rate limit reached"),
+            display("rate limit reached ({}), retry in {:?}", usage, retry_after),
+        }
+
+        FilterViolation(symbol: String, violation: FilterViolation) {
+            description("order rejected by the symbol filters"),
+            display("{}: {}", symbol, violation),
+        }
+
+        /// -2021 or -2022 from a spot cancel-replace, with the outcome of both halves.
+        CancelReplaceFailed(response: BinanceContentError, outcome: Box<OrderCancelReplace>) {
+            description("cancel-replace failed"),
+            display("{}", response),
+        }
      }
 
     foreign_links {
@@ -24,7 +227,82 @@ error_chain! {
         ParseFloatError(std::num::ParseFloatError);
         UrlParserError(url::ParseError);
         Json(serde_json::Error);
-        Tungstenite(tungstenite::Error);
+        // boxed, `tungstenite::Error` alone is larger than the rest of `Error`
+        Tungstenite(Box<tungstenite::Error>);
         TimestampError(std::time::SystemTimeError);
     }
 }
+
+impl From<tungstenite::Error> for Error {
+    fn from(error: tungstenite::Error) -> Self {
+        Box::new(error).into()
+    }
+}
+
+impl Error {
+    /// The error returned by Binance, if the request got a response.
+    pub fn binance_error(&self) -> Option<&BinanceContentError> {
+        match self.kind() {
+            ErrorKind::BinanceError(response) | ErrorKind::CancelReplaceFailed(response, _) => {
+                Some(response)
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a limit was hit: 429, 418 (IP banned), -1003/-1015, or the local rate limiter.
+    pub fn is_rate_limited(&self) -> bool {
+        match self.kind() {
+            ErrorKind::BinanceError(response) => {
+                response.status == 429
+                    || response.status == 418
+                    || matches!(
+                        response.error_code(),
+                        BinanceErrorCode::TooManyRequests | BinanceErrorCode::TooManyOrders
+                    )
+            }
+            ErrorKind::RateLimitExceeded(..) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the failure is transient and the same request may succeed later.
+    ///
+    /// This says nothing about whether it is safe to resend: on a 5xx or -1007 the
+    /// order may have been placed anyway.
+    pub fn is_retryable(&self) -> bool {
+        if self.is_rate_limited() {
+            return true;
+        }
+        match self.kind() {
+            ErrorKind::BinanceError(response) => {
+                response.status >= 500
+                    || matches!(
+                        response.error_code(),
+                        BinanceErrorCode::Disconnected
+                            | BinanceErrorCode::Timeout
+                            | BinanceErrorCode::ServerBusy
+                            | BinanceErrorCode::InvalidTimestamp
+                    )
+            }
+            _ => self.is_connect() || self.is_timeout(),
+        }
+    }
+
+    /// The connection could not be established, so the request never reached Binance.
+    pub(crate) fn is_connect(&self) -> bool {
+        match self.kind() {
+            ErrorKind::ReqError(error) => error.is_connect(),
+            ErrorKind::IoError(error) => error.kind() == std::io::ErrorKind::ConnectionRefused,
+            _ => false,
+        }
+    }
+
+    pub(crate) fn is_timeout(&self) -> bool {
+        match self.kind() {
+            ErrorKind::ReqError(error) => error.is_timeout(),
+            ErrorKind::IoError(error) => error.kind() == std::io::ErrorKind::TimedOut,
+            _ => false,
+        }
+    }
+}
@@ -1,20 +1,28 @@
+use error_chain::bail;
 use std::collections::BTreeMap;
 use std::fmt::Display;
 
-
 use crate::util::{build_signed_request, uuid_futures};
 use crate::errors::Result;
 use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
 use crate::api::{API, Futures};
-use crate::model::Empty;
+use crate::model::{Empty, Number};
 use crate::account::OrderSide;
-use crate::futures::model::{Order, TradeHistory};
+use crate::futures::model::{ExchangeInformation, Order, TradeHistory};
+use crate::validator::OrderValidator;
 
 use super::model::{
     ChangeLeverageResponse, Transaction, CanceledOrder, PositionRisk, AccountBalance,
     AccountInformation,
 };
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncFuturesAccount")
+)]
 #[derive(Clone)]
 pub struct FuturesAccount {
     pub client: Client,
@@ -134,21 +142,26 @@ struct OrderRequest {
     pub position_side: Option<PositionSide>,
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub qty: Option<f64>,
+    pub qty: Option<Number>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
     pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
+    pub activation_price: Option<Number>,
     pub callback_rate: Option<f64>,
     pub working_type: Option<WorkingType>,
     pub price_protect: Option<f64>,
     pub new_client_order_id: Option<String>,
     pub good_till_date: Option<u64>,
+    #[allow(dead_code)]
     pub algo_type: Option<AlgoType>,
     pub client_algo_id: Option<String>,
 }
 
+/// A futures order of any type, for `FuturesAccount::custom_order`.
+///
+/// Prices and quantities are sent as given: call `apply_filters` to round them to the
+/// symbol's tick and step size.
 pub struct CustomOrderRequest {
     // algoOrder params
     pub algo_type: AlgoType,
@@ -162,15 +175,15 @@ pub struct CustomOrderRequest {
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
     /// Cannot be sent with `closePosition`=true(Close-All)
-    pub qty: Option<f64>,
+    pub qty: Option<Number>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
+    pub price: Option<Number>,
     /// Used with `STOP`/`STOP_MARKET` or `TAKE_PROFIT`/`TAKE_PROFIT_MARKET` orders.
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Number>,
     /// Close-All，used with `STOP_MARKET` or `TAKE_PROFIT_MARKET`.
     pub close_position: Option<bool>,
     /// Used with `TRAILING_STOP_MARKET` orders, default as the latest price(supporting different `workingType`)
-    pub activation_price: Option<f64>,
+    pub activation_price: Option<Number>,
     /// Used with `TRAILING_STOP_MARKET` orders, min `0.1`, max `10` where `1` for `1%`
     pub callback_rate: Option<f64>,
     /// `stopPrice` triggered by: `MARK_PRICE`, `CONTRACT_PRICE`. Default `CONTRACT_PRICE`
@@ -187,6 +200,42 @@ pub struct CustomOrderRequest {
     pub good_till_date: Option<u64>,
 }
 
+impl CustomOrderRequest {
+    /// Rounds the prices to the tick size and the quantity to the step size, then checks
+    /// the order against the symbol's filters, the mark price being the `reference_price`.
+    pub fn apply_filters(
+        mut self, validator: &OrderValidator, reference_price: Option<Number>,
+    ) -> Result<Self> {
+        let market = matches!(
+            self.order_type,
+            OrderType::Market
+                | OrderType::StopMarket
+                | OrderType::TakeProfitMarket
+                | OrderType::TrailingStopMarket
+        );
+        self.price = self.price.map(|price| validator.round_price(price));
+        self.stop_price = self.stop_price.map(|price| validator.round_price(price));
+        self.activation_price = self
+            .activation_price
+            .map(|price| validator.round_price(price));
+        for price in self.stop_price.iter().chain(self.activation_price.iter()) {
+            validator.validate_price(*price)?;
+        }
+        // close-all orders have no quantity
+        if let Some(qty) = self.qty {
+            let qty = if market {
+                validator.round_market_quantity(qty)
+            } else {
+                validator.round_quantity(qty)
+            };
+            self.qty = Some(qty);
+            let price = if market { None } else { self.price };
+            validator.validate(&self.side, price, qty, reference_price)?;
+        }
+        Ok(self)
+    }
+}
+
 pub struct IncomeRequest {
     pub symbol: Option<String>,
     pub income_type: Option<IncomeType>,
@@ -244,9 +293,14 @@ impl Display for IncomeType {
     }
 }
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncFuturesAccount")
+)]
 impl FuturesAccount {
-    pub fn limit_buy(
-        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+    pub async fn limit_buy(
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: impl Into<Number>,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let buy = OrderRequest {
@@ -257,7 +311,7 @@ impl FuturesAccount {
             time_in_force: Some(time_in_force),
             qty: Some(qty.into()),
             reduce_only: None,
-            price: Some(price),
+            price: Some(price.into()),
             stop_price: None,
             close_position: None,
             activation_price: None,
@@ -273,10 +327,11 @@ impl FuturesAccount {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
+            .await
     }
 
-    pub fn limit_sell(
-        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+    pub async fn limit_sell(
+        &self, symbol: impl Into<String>, qty: impl Into<Number>, price: impl Into<Number>,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
         let sell = OrderRequest {
@@ -287,7 +342,7 @@ impl FuturesAccount {
             time_in_force: Some(time_in_force),
             qty: Some(qty.into()),
             reduce_only: None,
-            price: Some(price),
+            price: Some(price.into()),
             stop_price: None,
             close_position: None,
             activation_price: None,
@@ -303,13 +358,14 @@ impl FuturesAccount {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
+            .await
     }
 
     // Place a MARKET order - BUY
-    pub fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
@@ -335,13 +391,14 @@ impl FuturesAccount {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
+            .await
     }
 
     // Place a MARKET order - SELL
-    pub fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -367,9 +424,10 @@ impl FuturesAccount {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::Order), request)
+            .await
     }
 
-    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<CanceledOrder>
+    pub async fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<CanceledOrder>
     where
         S: Into<String>,
     {
@@ -380,9 +438,10 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed(API::Futures(Futures::Order), Some(request))
+            .await
     }
 
-    pub fn cancel_order_with_client_id<S>(
+    pub async fn cancel_order_with_client_id<S>(
         &self, symbol: S, orig_client_order_id: String,
     ) -> Result<CanceledOrder>
     where
@@ -395,13 +454,14 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed(API::Futures(Futures::Order), Some(request))
+            .await
     }
 
     // Place a STOP_MARKET close - BUY
-    pub fn stop_market_close_buy<S, F>(&self, symbol: S, stop_price: F) -> Result<Transaction>
+    pub async fn stop_market_close_buy<S, F>(&self, symbol: S, stop_price: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -427,13 +487,16 @@ impl FuturesAccount {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::AlgoOrder), request)
+            .await
     }
 
     // Place a STOP_MARKET close - SELL
-    pub fn stop_market_close_sell<S, F>(&self, symbol: S, stop_price: F) -> Result<Transaction>
+    pub async fn stop_market_close_sell<S, F>(
+        &self, symbol: S, stop_price: F,
+    ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Number>,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -459,15 +522,17 @@ impl FuturesAccount {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::AlgoOrder), request)
+            .await
     }
 
     // Custom order for for professional traders
-    pub fn custom_order(&self, order_request: CustomOrderRequest) -> Result<Transaction> {
+    pub async fn custom_order(&self, order_request: CustomOrderRequest) -> Result<Transaction> {
         self.custom_order_with_params(order_request, BTreeMap::new())
+            .await
     }
 
     // Custom order for for professional traders
-    pub fn custom_order_with_params(
+    pub async fn custom_order_with_params(
         &self, order_request: CustomOrderRequest, request_params: BTreeMap<String, String>,
     ) -> Result<Transaction> {
         let order = OrderRequest {
@@ -498,14 +563,15 @@ impl FuturesAccount {
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::AlgoOrder), request)
+            .await
     }
 
     // Custom order for for professional traders
-    pub fn custom_batch_orders_with_params(
+    pub async fn custom_batch_orders_with_params(
         &self, _order_count: u64, order_requests: Vec<CustomOrderRequest>,
         request_params: BTreeMap<String, String>,
     ) -> Result<Transaction> {
-        let request = String::from("");
+        let request = String::new();
         for order_request in order_requests {
             let order = OrderRequest {
                 symbol: order_request.symbol,
@@ -537,16 +603,18 @@ impl FuturesAccount {
         }
         self.client
             .post_signed(API::Futures(Futures::AlgoOrder), request)
+            .await
     }
 
     // Custom order for for professional traders
-    pub fn custom_batch_orders(
+    pub async fn custom_batch_orders(
         &self, _order_count: u64, order_requests: Vec<CustomOrderRequest>,
     ) -> Result<Transaction> {
         self.custom_batch_orders_with_params(_order_count, order_requests, BTreeMap::new())
+            .await
     }
 
-    pub fn get_all_orders<S, F, N>(
+    pub async fn get_all_orders<S, F, N>(
         &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
     ) -> Result<Vec<Order>>
     where
@@ -572,9 +640,10 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::AllOrders), Some(request))
+            .await
     }
 
-    pub fn get_user_trades<S, F, N>(
+    pub async fn get_user_trades<S, F, N>(
         &self, symbol: S, from_id: F, start_time: F, end_time: F, limit: N,
     ) -> Result<Vec<TradeHistory>>
     where
@@ -600,6 +669,7 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::UserTrades), Some(request))
+            .await
     }
     fn build_order(
         &self, order: OrderRequest, request_params: Option<BTreeMap<String, String>>,
@@ -613,9 +683,9 @@ impl FuturesAccount {
         if let Some(API::Futures(Futures::AlgoOrder)) = api_type {
             parameters.insert("algoType".into(), "Conditional".into());
             parameters.insert("clientAlgoId".into(), "Conditional".into());
-            
+
             if let Some(client_algo_id) = order.client_algo_id {
-                parameters.insert("clientAlgoId".into(), client_algo_id.to_string());
+                parameters.insert("clientAlgoId".into(), client_algo_id);
             }
 
             if let Some(stop_price) = order.stop_price {
@@ -675,14 +745,30 @@ impl FuturesAccount {
 
         if let Some(params) = request_params {
             for (key, value) in params {
-                parameters.insert(key, value.to_string());
+                parameters.insert(key, value);
             }
         }
 
         parameters
     }
 
-    pub fn position_information<S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
+    /// Validator for the filters of `symbol`, from the exchange information
+    pub async fn order_validator<S>(&self, symbol: S) -> Result<OrderValidator>
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into().to_uppercase();
+        let info: ExchangeInformation = self
+            .client
+            .get(API::Futures(Futures::ExchangeInfo), None)
+            .await?;
+        match info.symbols.iter().find(|item| item.symbol == symbol) {
+            Some(item) => OrderValidator::from_futures_symbol(item),
+            None => bail!("Symbol not found"),
+        }
+    }
+
+    pub async fn position_information<S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
     where
         S: Into<String>,
     {
@@ -692,25 +778,28 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::PositionRisk), Some(request))
+            .await
     }
 
-    pub fn account_information(&self) -> Result<AccountInformation> {
+    pub async fn account_information(&self) -> Result<AccountInformation> {
         let parameters = BTreeMap::new();
 
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::Account), Some(request))
+            .await
     }
 
-    pub fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+    pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
         let parameters = BTreeMap::new();
 
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::Balance), Some(request))
+            .await
     }
 
-    pub fn change_initial_leverage<S>(
+    pub async fn change_initial_leverage<S>(
         &self, symbol: S, leverage: u8,
     ) -> Result<ChangeLeverageResponse>
     where
@@ -723,9 +812,10 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed(API::Futures(Futures::ChangeInitialLeverage), request)
+            .await
     }
 
-    pub fn change_margin_type<S>(&self, symbol: S, isolated: bool) -> Result<()>
+    pub async fn change_margin_type<S>(&self, symbol: S, isolated: bool) -> Result<()>
     where
         S: Into<String>,
     {
@@ -737,10 +827,11 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Futures(Futures::MarginType), request)
+            .await
             .map(|_| ())
     }
 
-    pub fn change_position_margin<S>(
+    pub async fn change_position_margin<S>(
         &self, symbol: S, amount: f64, is_adding_margin: bool,
     ) -> Result<()>
     where
@@ -755,10 +846,11 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Futures(Futures::PositionMargin), request)
+            .await
             .map(|_| ())
     }
 
-    pub fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+    pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         let dual_side = if dual_side_position { "true" } else { "false" };
         parameters.insert("dualSidePosition".into(), dual_side.into());
@@ -766,10 +858,11 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Futures(Futures::PositionSide), request)
+            .await
             .map(|_| ())
     }
 
-    pub fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
     where
         S: Into<String>,
     {
@@ -778,10 +871,13 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .delete_signed::<Empty>(API::Futures(Futures::AllOpenOrders), Some(request))
+            .await
             .map(|_| ())
     }
 
-    pub fn get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<crate::futures::model::Order>>
+    pub async fn get_all_open_orders<S>(
+        &self, symbol: S,
+    ) -> Result<Vec<crate::futures::model::Order>>
     where
         S: Into<String>,
     {
@@ -790,9 +886,10 @@ impl FuturesAccount {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::OpenOrders), Some(request))
+            .await
     }
 
-    pub fn get_income(
+    pub async fn get_income(
         &self, income_request: IncomeRequest,
     ) -> Result<Vec<crate::futures::model::Income>> {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
@@ -813,8 +910,8 @@ impl FuturesAccount {
         }
 
         let request = build_signed_request(parameters, self.recv_window)?;
-        println!("{}", request);
         self.client
             .get_signed(API::Futures(Futures::Income), Some(request))
+            .await
     }
 }
@@ -0,0 +1,472 @@
+use error_chain::bail;
+use std::collections::BTreeMap;
+
+use crate::util::{build_signed_request, uuid_futures};
+use crate::errors::Result;
+use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+use crate::api::{API, CoinM};
+use crate::model::{Empty, Number};
+use crate::account::OrderSide;
+use crate::futures::account::{OrderType, PositionSide, TimeInForce, WorkingType};
+use crate::futures::coinm::model::{
+    AccountBalance, AccountInformation, ChangeLeverageResponse, ExchangeInformation, Order,
+    PositionRisk, TradeHistory,
+};
+use crate::validator::OrderValidator;
+
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncCoinMAccount")
+)]
+#[derive(Clone)]
+pub struct CoinMAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Order on a COIN-M symbol, the quantity being a whole number of contracts.
+pub struct CoinMOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    /// Default `BOTH` for One-way Mode ; `LONG` or `SHORT` for Hedge Mode.
+    pub position_side: Option<PositionSide>,
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    /// Contracts, cannot be sent with `closePosition`
+    pub quantity: Option<u64>,
+    pub reduce_only: Option<bool>,
+    pub price: Option<Number>,
+    /// Used with `STOP`/`STOP_MARKET` or `TAKE_PROFIT`/`TAKE_PROFIT_MARKET` orders.
+    pub stop_price: Option<Number>,
+    /// Close-All, used with `STOP_MARKET` or `TAKE_PROFIT_MARKET`.
+    pub close_position: Option<bool>,
+    /// Used with `TRAILING_STOP_MARKET` orders
+    pub activation_price: Option<Number>,
+    /// Used with `TRAILING_STOP_MARKET` orders, min `0.1`, max `10` where `1` for `1%`
+    pub callback_rate: Option<f64>,
+    pub working_type: Option<WorkingType>,
+    pub price_protect: Option<bool>,
+    /// Automatically generated if not sent.
+    pub new_client_order_id: Option<String>,
+}
+
+impl CoinMOrderRequest {
+    pub fn new<S: Into<String>>(symbol: S, side: OrderSide, order_type: OrderType) -> Self {
+        CoinMOrderRequest {
+            symbol: symbol.into(),
+            side,
+            position_side: None,
+            order_type,
+            time_in_force: None,
+            quantity: None,
+            reduce_only: None,
+            price: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            new_client_order_id: None,
+        }
+    }
+
+    pub fn set_position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_quantity(mut self, contracts: u64) -> Self {
+        self.quantity = Some(contracts);
+        self
+    }
+
+    pub fn set_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    pub fn set_price<F: Into<Number>>(mut self, price: F) -> Self {
+        self.price = Some(price.into());
+        self
+    }
+
+    pub fn set_stop_price<F: Into<Number>>(mut self, stop_price: F) -> Self {
+        self.stop_price = Some(stop_price.into());
+        self
+    }
+
+    pub fn set_close_position(mut self, close_position: bool) -> Self {
+        self.close_position = Some(close_position);
+        self
+    }
+
+    pub fn set_activation_price<F: Into<Number>>(mut self, activation_price: F) -> Self {
+        self.activation_price = Some(activation_price.into());
+        self
+    }
+
+    pub fn set_callback_rate(mut self, callback_rate: f64) -> Self {
+        self.callback_rate = Some(callback_rate);
+        self
+    }
+
+    pub fn set_working_type(mut self, working_type: WorkingType) -> Self {
+        self.working_type = Some(working_type);
+        self
+    }
+
+    pub fn set_price_protect(mut self, price_protect: bool) -> Self {
+        self.price_protect = Some(price_protect);
+        self
+    }
+
+    pub fn set_new_client_order_id<S: Into<String>>(mut self, new_client_order_id: S) -> Self {
+        self.new_client_order_id = Some(new_client_order_id.into());
+        self
+    }
+
+    fn into_parameters(self) -> BTreeMap<String, String> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), self.symbol);
+        parameters.insert("side".into(), self.side.to_string());
+        parameters.insert("type".into(), self.order_type.to_string());
+
+        if let Some(position_side) = self.position_side {
+            parameters.insert("positionSide".into(), position_side.to_string());
+        }
+        if let Some(time_in_force) = self.time_in_force {
+            parameters.insert("timeInForce".into(), time_in_force.to_string());
+        }
+        if let Some(quantity) = self.quantity {
+            parameters.insert("quantity".into(), quantity.to_string());
+        }
+        if let Some(reduce_only) = self.reduce_only {
+            parameters.insert("reduceOnly".into(), reduce_only.to_string());
+        }
+        if let Some(price) = self.price {
+            parameters.insert("price".into(), price.to_string());
+        }
+        if let Some(stop_price) = self.stop_price {
+            parameters.insert("stopPrice".into(), stop_price.to_string());
+        }
+        if let Some(close_position) = self.close_position {
+            parameters.insert("closePosition".into(), close_position.to_string());
+        }
+        if let Some(activation_price) = self.activation_price {
+            parameters.insert("activationPrice".into(), activation_price.to_string());
+        }
+        if let Some(callback_rate) = self.callback_rate {
+            parameters.insert("callbackRate".into(), callback_rate.to_string());
+        }
+        if let Some(working_type) = self.working_type {
+            parameters.insert("workingType".into(), working_type.to_string());
+        }
+        if let Some(price_protect) = self.price_protect {
+            parameters.insert(
+                "priceProtect".into(),
+                price_protect.to_string().to_uppercase(),
+            );
+        }
+        let new_client_order_id = self.new_client_order_id.unwrap_or_else(uuid_futures);
+        parameters.insert("newClientOrderId".into(), new_client_order_id);
+
+        parameters
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncCoinMAccount")
+)]
+impl CoinMAccount {
+    // Place a LIMIT order - BUY, for a number of contracts
+    pub async fn limit_buy<S, F>(
+        &self, symbol: S, contracts: u64, price: F, time_in_force: TimeInForce,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let order = CoinMOrderRequest::new(symbol, OrderSide::Buy, OrderType::Limit)
+            .set_quantity(contracts)
+            .set_price(price)
+            .set_time_in_force(time_in_force);
+        self.place_order(order).await
+    }
+
+    // Place a LIMIT order - SELL, for a number of contracts
+    pub async fn limit_sell<S, F>(
+        &self, symbol: S, contracts: u64, price: F, time_in_force: TimeInForce,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let order = CoinMOrderRequest::new(symbol, OrderSide::Sell, OrderType::Limit)
+            .set_quantity(contracts)
+            .set_price(price)
+            .set_time_in_force(time_in_force);
+        self.place_order(order).await
+    }
+
+    // Place a MARKET order - BUY, for a number of contracts
+    pub async fn market_buy<S>(&self, symbol: S, contracts: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let order = CoinMOrderRequest::new(symbol, OrderSide::Buy, OrderType::Market)
+            .set_quantity(contracts);
+        self.place_order(order).await
+    }
+
+    // Place a MARKET order - SELL, for a number of contracts
+    pub async fn market_sell<S>(&self, symbol: S, contracts: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let order = CoinMOrderRequest::new(symbol, OrderSide::Sell, OrderType::Market)
+            .set_quantity(contracts);
+        self.place_order(order).await
+    }
+
+    pub async fn place_order(&self, order: CoinMOrderRequest) -> Result<Order> {
+        let request = build_signed_request(order.into_parameters(), self.recv_window)?;
+        self.client
+            .post_signed(API::CoinM(CoinM::Order), request)
+            .await
+    }
+
+    pub async fn get_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinM(CoinM::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::CoinM(CoinM::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::CoinM(CoinM::AllOpenOrders), Some(request))
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinM(CoinM::OpenOrders), Some(request))
+            .await
+    }
+
+    pub async fn get_all_orders<S, F, N>(
+        &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = order_id.into() {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinM(CoinM::AllOrders), Some(request))
+            .await
+    }
+
+    pub async fn get_user_trades<S, F, N>(
+        &self, symbol: S, from_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(from_id) = from_id.into() {
+            parameters.insert("fromId".into(), from_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinM(CoinM::UserTrades), Some(request))
+            .await
+    }
+
+    /// Validator for the filters of `symbol`, from the exchange information.
+    /// Quantities are validated in contracts.
+    pub async fn order_validator<S>(&self, symbol: S) -> Result<OrderValidator>
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into().to_uppercase();
+        let info: ExchangeInformation = self
+            .client
+            .get(API::CoinM(CoinM::ExchangeInfo), None)
+            .await?;
+        match info.symbols.iter().find(|item| item.symbol == symbol) {
+            Some(item) => OrderValidator::new(item.symbol.clone(), &item.filters),
+            None => bail!("Symbol not found"),
+        }
+    }
+
+    // Positions of all the symbols, or of the symbols of one pair (e.g. "BTCUSD")
+    pub async fn position_information(&self, pair: Option<&str>) -> Result<Vec<PositionRisk>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(pair) = pair {
+            parameters.insert("pair".into(), pair.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinM(CoinM::PositionRisk), Some(request))
+            .await
+    }
+
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        let parameters = BTreeMap::new();
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinM(CoinM::Account), Some(request))
+            .await
+    }
+
+    pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+        let parameters = BTreeMap::new();
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::CoinM(CoinM::Balance), Some(request))
+            .await
+    }
+
+    pub async fn change_initial_leverage<S>(
+        &self, symbol: S, leverage: u8,
+    ) -> Result<ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("leverage".into(), leverage.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::CoinM(CoinM::ChangeInitialLeverage), request)
+            .await
+    }
+
+    pub async fn change_margin_type<S>(&self, symbol: S, isolated: bool) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        let margin_type = if isolated { "ISOLATED" } else { "CROSSED" };
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("marginType".into(), margin_type.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::CoinM(CoinM::MarginType), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Add or reduce the isolated margin of a position, amount in the margin asset
+    pub async fn change_position_margin<S, F>(
+        &self, symbol: S, amount: F, is_adding_margin: bool,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: Into<Number>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        let margin = if is_adding_margin { "1" } else { "2" };
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("amount".into(), amount.into().to_string());
+        parameters.insert("type".into(), margin.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::CoinM(CoinM::PositionMargin), request)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        let dual_side = if dual_side_position { "true" } else { "false" };
+        parameters.insert("dualSidePosition".into(), dual_side.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::CoinM(CoinM::PositionSide), request)
+            .await
+            .map(|_| ())
+    }
+}
@@ -0,0 +1,89 @@
+use error_chain::bail;
+
+use crate::config::{COINM_MAINNET, COINM_TESTNET};
+use crate::model::Empty;
+use crate::futures::coinm::model::{ExchangeInformation, ServerTime, Symbol};
+use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+use crate::errors::Result;
+use crate::api::API;
+use crate::api::CoinM;
+
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncCoinMGeneral")
+)]
+#[derive(Clone)]
+pub struct CoinMGeneral {
+    pub client: Client,
+}
+
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncCoinMGeneral")
+)]
+impl CoinMGeneral {
+    // Test connectivity
+    pub async fn ping(&self) -> Result<String> {
+        self.client
+            .get::<Empty>(API::CoinM(CoinM::Ping), None)
+            .await?;
+        Ok("pong".into())
+    }
+
+    pub fn set_verbose(&mut self, verbose: bool) {
+        self.client.set_verbose(verbose);
+    }
+
+    pub fn set_testnet(&mut self, testnet: bool) {
+        if testnet {
+            self.client.set_host(COINM_TESTNET.into());
+        } else {
+            self.client.set_host(COINM_MAINNET.into());
+        }
+    }
+
+    // Check server time
+    pub async fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get(API::CoinM(CoinM::Time), None).await
+    }
+
+    // Measure the offset between the local clock and the server time, in ms
+    // - Applied to signed requests when clock sync is enabled in the Config
+    pub async fn sync_clock(&self) -> Result<i64> {
+        self.client.sync_clock(API::CoinM(CoinM::Time)).await
+    }
+
+    // Obtain exchange information
+    // - Trading rules, contract sizes and delivery dates of the symbols
+    // Also sets the limits used by the client's rate limiter
+    pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
+        let info: ExchangeInformation = self
+            .client
+            .get(API::CoinM(CoinM::ExchangeInfo), None)
+            .await?;
+        self.client
+            .set_rate_limits(API::CoinM(CoinM::ExchangeInfo), &info.rate_limits);
+        Ok(info)
+    }
+
+    // Get Symbol information, e.g. "BTCUSD_PERP" or "BTCUSD_250627"
+    pub async fn get_symbol_info<S>(&self, symbol: S) -> Result<Symbol>
+    where
+        S: Into<String>,
+    {
+        let upper_symbol = symbol.into().to_uppercase();
+        let info = self.exchange_info().await?;
+        match info
+            .symbols
+            .into_iter()
+            .find(|item| item.symbol == upper_symbol)
+        {
+            Some(item) => Ok(item),
+            None => bail!("Symbol not found"),
+        }
+    }
+}
@@ -0,0 +1,371 @@
+/*!
+## Implemented functionality
+- [x] `Order Book`
+- [x] `Recent Trades List`
+- [ ] `Old Trades Lookup (MARKET_DATA)`
+- [x] `Compressed/Aggregate Trades List`
+- [x] `Kline/Candlestick Data`
+- [x] `Continuous Contract Kline/Candlestick Data`
+- [x] `Index Price Kline/Candlestick Data`
+- [x] `Mark Price Kline/Candlestick Data`
+- [x] `Index Price and Mark Price`
+- [x] `Get Funding Rate History of Perpetual Futures`
+- [x] `24hr Ticker Price Change Statistics`
+- [x] `Symbol Price Ticker`
+- [x] `Symbol Order Book Ticker`
+- [x] `Open Interest`
+*/
+
+use error_chain::bail;
+
+use crate::util::build_request;
+use crate::futures::account::ContractType;
+use crate::futures::market::add_time_range;
+use crate::futures::coinm::model::{
+    AggTrades, BookTicker, FundingRate, KlineSummaries, KlineSummary, MarkPrice, OpenInterest,
+    OrderBook, PriceStats, SymbolPrice, Trade,
+};
+use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use serde_json::Value;
+use crate::api::API;
+use crate::api::CoinM;
+
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncCoinMMarket")
+)]
+#[derive(Clone)]
+pub struct CoinMMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncCoinMMarket")
+)]
+impl CoinMMarket {
+    // Order book (Default 500; 5, 10, 20, 50, 100, 500 or 1000), quantities in contracts
+    pub async fn get_depth<S, N>(&self, symbol: S, limit: N) -> Result<OrderBook>
+    where
+        S: Into<String>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinM(CoinM::Depth), Some(request))
+            .await
+    }
+
+    // Recent trades (Default 500; max 1000)
+    pub async fn get_trades<S, N>(&self, symbol: S, limit: N) -> Result<Vec<Trade>>
+    where
+        S: Into<String>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinM(CoinM::Trades), Some(request))
+            .await
+    }
+
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, from_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<AggTrades>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", fi));
+        }
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinM(CoinM::AggTrades), Some(request))
+            .await
+    }
+
+    // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    // The volume is in contracts, the quote asset volume column in the base asset.
+    pub async fn get_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        self.klines(API::CoinM(CoinM::Klines), parameters).await
+    }
+
+    // Klines of a contract type (perpetual, current or next quarter) of a pair, e.g. "BTCUSD"
+    pub async fn get_continuous_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, contract_type: ContractType, interval: S2, limit: S3, start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("contractType".into(), contract_type.into());
+        parameters.insert("interval".into(), interval.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        self.klines(API::CoinM(CoinM::ContinuousKlines), parameters)
+            .await
+    }
+
+    // Index price klines of a pair. The volume and trade count columns are not used (zero).
+    pub async fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("interval".into(), interval.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        self.klines(API::CoinM(CoinM::IndexPriceKlines), parameters)
+            .await
+    }
+
+    // Mark price klines of a symbol. The volume and trade count columns are not used (zero).
+    pub async fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        self.klines(API::CoinM(CoinM::MarkPriceKlines), parameters)
+            .await
+    }
+
+    async fn klines(
+        &self, endpoint: API, parameters: BTreeMap<String, String>,
+    ) -> Result<KlineSummaries> {
+        let request = build_request(parameters);
+        let data: Vec<Vec<Value>> = self.client.get(endpoint, Some(request)).await?;
+
+        let klines = KlineSummaries::AllKlineSummaries(
+            data.iter()
+                .map(|row| row.try_into())
+                .collect::<Result<Vec<KlineSummary>>>()?,
+        );
+
+        Ok(klines)
+    }
+
+    // Mark price, index price and funding rate of all symbols
+    pub async fn get_mark_prices(&self) -> Result<Vec<MarkPrice>> {
+        self.client.get(API::CoinM(CoinM::PremiumIndex), None).await
+    }
+
+    // Mark price, index price and funding rate for ONE symbol
+    pub async fn get_mark_price<S>(&self, symbol: S) -> Result<MarkPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        let mark_prices: Vec<MarkPrice> = self
+            .client
+            .get(API::CoinM(CoinM::PremiumIndex), Some(request))
+            .await?;
+        first(mark_prices)
+    }
+
+    // Mark prices of the perpetual and delivery contracts of a pair, e.g. "BTCUSD"
+    pub async fn get_pair_mark_prices<S>(&self, pair: S) -> Result<Vec<MarkPrice>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("pair".into(), pair.into());
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinM(CoinM::PremiumIndex), Some(request))
+            .await
+    }
+
+    // Funding rates of ONE perpetual symbol, in ascending order. Without start_time and
+    // end_time, the most recent 'limit' (default 100, max 1000) are returned.
+    pub async fn get_funding_rate_history<S, F, N>(
+        &self, symbol: S, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinM(CoinM::FundingRate), Some(request))
+            .await
+    }
+
+    // 24hr ticker price change statistics
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        let stats: Vec<PriceStats> = self
+            .client
+            .get(API::CoinM(CoinM::Ticker24hr), Some(request))
+            .await?;
+        first(stats)
+    }
+
+    // 24hr ticker price change statistics for all symbols
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.client.get(API::CoinM(CoinM::Ticker24hr), None).await
+    }
+
+    // Latest price for ONE symbol.
+    pub async fn get_price<S>(&self, symbol: S) -> Result<SymbolPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        let prices: Vec<SymbolPrice> = self
+            .client
+            .get(API::CoinM(CoinM::TickerPrice), Some(request))
+            .await?;
+        first(prices)
+    }
+
+    // Latest price for all symbols.
+    pub async fn get_all_prices(&self) -> Result<Vec<SymbolPrice>> {
+        self.client.get(API::CoinM(CoinM::TickerPrice), None).await
+    }
+
+    // -> Best price/qty on the order book for ONE symbol
+    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<BookTicker>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        let tickers: Vec<BookTicker> = self
+            .client
+            .get(API::CoinM(CoinM::BookTicker), Some(request))
+            .await?;
+        first(tickers)
+    }
+
+    // -> Best price/qty on the order book for ALL symbols.
+    pub async fn get_all_book_tickers(&self) -> Result<Vec<BookTicker>> {
+        self.client.get(API::CoinM(CoinM::BookTicker), None).await
+    }
+
+    // Open interest of a symbol, in contracts
+    pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get(API::CoinM(CoinM::OpenInterest), Some(request))
+            .await
+    }
+}
+
+// The ticker and premium index endpoints answer with a list, even for one symbol
+fn first<T>(items: Vec<T>) -> Result<T> {
+    match items.into_iter().next() {
+        Some(item) => Ok(item),
+        None => bail!("Symbol not found"),
+    }
+}
@@ -0,0 +1,10 @@
+//! COIN-M futures (`dapi.binance.com`): delivery and perpetual contracts margined and
+//! settled in the base asset.
+//!
+//! Quantities are numbers of contracts, each worth `Symbol::contract_size` USD, while
+//! balances, margins and profits are in the margin asset (e.g. BTC for `BTCUSD_PERP`).
+
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod model;
@@ -0,0 +1,426 @@
+use serde::{Deserialize, Serialize};
+use crate::model::{
+    string_or_bool, string_or_float, string_or_float_opt, to_number, Number, NumberString,
+};
+
+pub use crate::futures::model::{
+    AggTrade, AggTrades, Asks, Bids, ExchangeFilter, Filters, FundingRate, KlineSummaries,
+    KlineSummary, RateLimit, ServerTime,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub exchange_filters: Vec<ExchangeFilter>,
+    pub symbols: Vec<Symbol>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub pair: String,
+    // PERPETUAL, CURRENT_QUARTER or NEXT_QUARTER
+    pub contract_type: String,
+    // 4133404800000 for perpetual contracts
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: String,
+    // USD value of one contract
+    pub contract_size: u64,
+    pub margin_asset: String,
+    pub maint_margin_percent: String,
+    pub required_margin_percent: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub price_precision: u16,
+    pub quantity_precision: u16,
+    pub base_asset_precision: u64,
+    pub quote_precision: u64,
+    #[serde(default)]
+    pub equal_qty_precision: u64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub trigger_protect: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub liquidation_fee: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub market_take_bound: Option<Number>,
+    pub filters: Vec<Filters>,
+    #[serde(rename = "OrderType", alias = "orderTypes", default)]
+    pub order_types: Vec<String>,
+    pub time_in_force: Vec<String>,
+}
+
+impl Symbol {
+    // Value of a number of contracts in the base (and margin) asset, at a price
+    // - None if the price is not positive or the value overflows
+    pub fn base_qty(&self, contracts: Number, price: Number) -> Option<Number> {
+        let contract_size = to_number(self.contract_size as f64);
+        #[cfg(not(feature = "decimal"))]
+        return (price > 0.0)
+            .then(|| contracts * contract_size / price)
+            .filter(|base_qty| base_qty.is_finite());
+        #[cfg(feature = "decimal")]
+        return (price > Number::ZERO)
+            .then(|| contracts.checked_mul(contract_size)?.checked_div(price))
+            .flatten();
+    }
+
+    // Whole number of contracts worth at most base_qty of the base asset, at a price
+    // - None if the price is not positive, or base_qty is negative or too large
+    pub fn contracts(&self, base_qty: Number, price: Number) -> Option<u64> {
+        let contract_size = to_number(self.contract_size as f64);
+        #[cfg(not(feature = "decimal"))]
+        let contracts = (price > 0.0).then(|| base_qty * price / contract_size);
+        #[cfg(feature = "decimal")]
+        let contracts = (price > Number::ZERO)
+            .then(|| base_qty.checked_mul(price)?.checked_div(contract_size))
+            .flatten();
+        contracts.and_then(whole_contracts)
+    }
+}
+
+#[cfg(not(feature = "decimal"))]
+fn whole_contracts(contracts: Number) -> Option<u64> {
+    let contracts = contracts.floor();
+    if contracts >= 0.0 && contracts < u64::MAX as f64 {
+        Some(contracts as u64)
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "decimal")]
+fn whole_contracts(contracts: Number) -> Option<u64> {
+    u64::try_from(contracts.floor()).ok()
+}
+
+// Quantities are in contracts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBook {
+    pub last_update_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub trade_order_time: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    // contracts
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub base_qty: Number,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float")]
+    pub index_price: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub estimated_settle_price: Option<Number>,
+    // empty for delivery contracts
+    #[serde(default, with = "string_or_float_opt")]
+    pub last_funding_rate: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub interest_rate: Option<Number>,
+    // 0 for delivery contracts
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceStats {
+    pub symbol: String,
+    pub pair: String,
+    pub price_change: NumberString,
+    pub price_change_percent: String,
+    pub weighted_avg_price: NumberString,
+    #[serde(with = "string_or_float")]
+    pub last_price: Number,
+    #[serde(with = "string_or_float")]
+    pub last_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub open_price: Number,
+    #[serde(with = "string_or_float")]
+    pub high_price: Number,
+    #[serde(with = "string_or_float")]
+    pub low_price: Number,
+    // contracts
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    #[serde(with = "string_or_float")]
+    pub base_volume: Number,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: u64,
+    pub last_id: u64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolPrice {
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub bid_price: Number,
+    #[serde(with = "string_or_float")]
+    pub bid_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub ask_price: Number,
+    #[serde(with = "string_or_float")]
+    pub ask_qty: Number,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    pub pair: String,
+    // contracts
+    #[serde(with = "string_or_float")]
+    pub open_interest: Number,
+    pub contract_type: String,
+    pub time: u64,
+}
+
+// Returned when placing, querying and canceling orders
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub client_order_id: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cum_qty: Option<Number>,
+    #[serde(with = "string_or_float")]
+    pub cum_base: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    pub reduce_only: bool,
+    pub side: String,
+    pub position_side: String,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub stop_price: Number,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub orig_type: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub activate_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price_rate: Option<Number>,
+    // only when querying orders
+    #[serde(default)]
+    pub time: u64,
+    pub update_time: u64,
+    pub working_type: String,
+    pub price_protect: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeHistory {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub pair: String,
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    // contracts
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: Number,
+    pub margin_asset: String,
+    #[serde(with = "string_or_float")]
+    pub base_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub commission: Number,
+    pub commission_asset: String,
+    pub time: u64,
+    pub position_side: String,
+    pub buyer: bool,
+    pub maker: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub break_even_price: Option<Number>,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float", rename = "unRealizedProfit")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: Number,
+    pub leverage: String,
+    // maximum position at the current leverage, in contracts
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    pub margin_type: String,
+    #[serde(with = "string_or_float")]
+    pub isolated_margin: Number,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub position_side: String,
+    // in the margin asset
+    #[serde(with = "string_or_float")]
+    pub notional_value: Number,
+    #[serde(with = "string_or_float")]
+    pub isolated_wallet: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_un_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub available_balance: Number,
+    #[serde(default)]
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: Number,
+    pub leverage: String,
+    #[serde(with = "string_or_bool")]
+    pub isolated: bool,
+    pub position_side: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub break_even_price: Option<Number>,
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub notional_value: Number,
+    #[serde(with = "string_or_float")]
+    pub isolated_wallet: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub assets: Vec<CoinMAsset>,
+    pub positions: Vec<CoinMPosition>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    pub account_alias: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub balance: Number,
+    #[serde(with = "string_or_float")]
+    pub withdraw_available: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: Number,
+    #[serde(with = "string_or_float", rename = "crossUnPnl")]
+    pub cross_unrealized_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub available_balance: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageResponse {
+    pub leverage: u8,
+    // in contracts
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    pub symbol: String,
+}
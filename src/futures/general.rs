@@ -4,20 +4,33 @@ use crate::config::{FUTURES_MAINNET, FUTURES_TESTNET};
 use crate::model::Empty;
 use crate::futures::model::{ExchangeInformation, ServerTime, Symbol};
 use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
 use crate::errors::Result;
 use crate::api::API;
 use crate::api::Futures;
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncFuturesGeneral")
+)]
 #[derive(Clone)]
 pub struct FuturesGeneral {
     pub client: Client,
 }
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncFuturesGeneral")
+)]
 impl FuturesGeneral {
     // Test connectivity
-    pub fn ping(&self) -> Result<String> {
+    pub async fn ping(&self) -> Result<String> {
         self.client
-            .get::<Empty>(API::Futures(Futures::Ping), None)?;
+            .get::<Empty>(API::Futures(Futures::Ping), None)
+            .await?;
         Ok("pong".into())
     }
 
@@ -34,23 +47,36 @@ impl FuturesGeneral {
     }
 
     // Check server time
-    pub fn get_server_time(&self) -> Result<ServerTime> {
-        self.client.get(API::Futures(Futures::Time), None)
+    pub async fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get(API::Futures(Futures::Time), None).await
+    }
+
+    // Measure the offset between the local clock and the server time, in ms
+    // - Applied to signed requests when clock sync is enabled in the Config
+    pub async fn sync_clock(&self) -> Result<i64> {
+        self.client.sync_clock(API::Futures(Futures::Time)).await
     }
 
     // Obtain exchange information
     // - Current exchange trading rules and symbol information
-    pub fn exchange_info(&self) -> Result<ExchangeInformation> {
-        self.client.get(API::Futures(Futures::ExchangeInfo), None)
+    // Also sets the limits used by the client's rate limiter
+    pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
+        let info: ExchangeInformation = self
+            .client
+            .get(API::Futures(Futures::ExchangeInfo), None)
+            .await?;
+        self.client
+            .set_rate_limits(API::Futures(Futures::ExchangeInfo), &info.rate_limits);
+        Ok(info)
     }
 
     // Get Symbol information
-    pub fn get_symbol_info<S>(&self, symbol: S) -> Result<Symbol>
+    pub async fn get_symbol_info<S>(&self, symbol: S) -> Result<Symbol>
     where
         S: Into<String>,
     {
         let upper_symbol = symbol.into().to_uppercase();
-        match self.exchange_info() {
+        match self.exchange_info().await {
             Ok(info) => {
                 for item in info.symbols {
                     if item.symbol == upper_symbol {
@@ -6,26 +6,32 @@
 - [x] `Compressed/Aggregate Trades List`
 - [x] `Kline/Candlestick Data`
 - [x] `Mark Price`
-- [ ] `Get Funding Rate History (MARKET_DATA)`
+- [x] `Get Funding Rate History (MARKET_DATA)`
+- [x] `Get Funding Rate Info`
 - [x] `24hr Ticker Price Change Statistics`
 - [x] `Symbol Price Ticker`
 - [x] `Symbol Order Book Ticker`
 - [x] `Get all Liquidation Orders`
 - [x] `Open Interest`
 - [ ] `Notional and Leverage Brackets (MARKET_DATA)`
-- [ ] `Open Interest Statistics (MARKET_DATA)`
-- [ ] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
-- [ ] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
-- [ ] `Long/Short Ratio (MARKET_DATA)`
-- [ ] `Taker Buy/Sell Volume (MARKET_DATA)`
+- [x] `Open Interest Statistics (MARKET_DATA)`
+- [x] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
+- [x] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
+- [x] `Long/Short Ratio (MARKET_DATA)`
+- [x] `Taker Buy/Sell Volume (MARKET_DATA)`
+- [x] `Basis (MARKET_DATA)`
 */
 
 use crate::util::{build_request, build_signed_request};
+use crate::futures::account::ContractType;
 use crate::futures::model::{
-    AggTrades, BookTickers, KlineSummaries, KlineSummary, LiquidationOrders, MarkPrices,
-    OpenInterest, OpenInterestHist, OrderBook, PriceStats, SymbolPrice, Tickers, Trades,
+    AggTrades, Basis, BookTickers, FundingInfo, FundingRate, KlineSummaries, KlineSummary,
+    LiquidationOrders, LongShortRatio, MarkPrice, MarkPrices, OpenInterest, OpenInterestHist,
+    OrderBook, PriceStats, SymbolPrice, TakerBuySellVolume, Tickers, Trades,
 };
 use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
 use crate::errors::Result;
 use std::collections::BTreeMap;
 use serde_json::Value;
@@ -37,15 +43,25 @@ use crate::api::Futures;
 // Add limit parameters to functions
 // Implement all functions
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncFuturesMarket")
+)]
 #[derive(Clone)]
 pub struct FuturesMarket {
     pub client: Client,
     pub recv_window: u64,
 }
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncFuturesMarket")
+)]
 impl FuturesMarket {
     // Order book (Default 100; max 1000)
-    pub fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
     where
         S: Into<String>,
     {
@@ -54,12 +70,14 @@ impl FuturesMarket {
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
 
-        self.client.get(API::Futures(Futures::Depth), Some(request))
+        self.client
+            .get(API::Futures(Futures::Depth), Some(request))
+            .await
     }
 
     // Order book at a custom depth. Currently supported values
     // are 5, 10, 20, 50, 100, 500, 1000
-    pub fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
+    pub async fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
     where
         S: Into<String>,
     {
@@ -67,10 +85,12 @@ impl FuturesMarket {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("limit".into(), depth.to_string());
         let request = build_request(parameters);
-        self.client.get(API::Futures(Futures::Depth), Some(request))
+        self.client
+            .get(API::Futures(Futures::Depth), Some(request))
+            .await
     }
 
-    pub fn get_trades<S>(&self, symbol: S) -> Result<Trades>
+    pub async fn get_trades<S>(&self, symbol: S) -> Result<Trades>
     where
         S: Into<String>,
     {
@@ -79,10 +99,11 @@ impl FuturesMarket {
         let request = build_request(parameters);
         self.client
             .get(API::Futures(Futures::Trades), Some(request))
+            .await
     }
 
     // TODO This may be incomplete, as it hasn't been tested
-    pub fn get_historical_trades<S1, S2, S3>(
+    pub async fn get_historical_trades<S1, S2, S3>(
         &self, symbol: S1, from_id: S2, limit: S3,
     ) -> Result<Trades>
     where
@@ -106,9 +127,10 @@ impl FuturesMarket {
 
         self.client
             .get_signed(API::Futures(Futures::HistoricalTrades), Some(request))
+            .await
     }
 
-    pub fn get_agg_trades<S1, S2, S3, S4, S5>(
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
         &self, symbol: S1, from_id: S2, start_time: S3, end_time: S4, limit: S5,
     ) -> Result<AggTrades>
     where
@@ -140,11 +162,12 @@ impl FuturesMarket {
 
         self.client
             .get(API::Futures(Futures::AggTrades), Some(request))
+            .await
     }
 
     // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
     // https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data
-    pub fn get_klines<S1, S2, S3, S4, S5>(
+    pub async fn get_klines<S1, S2, S3, S4, S5>(
         &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
     ) -> Result<KlineSummaries>
     where
@@ -158,23 +181,127 @@ impl FuturesMarket {
 
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("interval".into(), interval.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
 
-        // Add three optional parameters
-        if let Some(lt) = limit.into() {
-            parameters.insert("limit".into(), format!("{}", lt));
-        }
-        if let Some(st) = start_time.into() {
-            parameters.insert("startTime".into(), format!("{}", st));
-        }
-        if let Some(et) = end_time.into() {
-            parameters.insert("endTime".into(), format!("{}", et));
-        }
+        self.klines(API::Futures(Futures::Klines), parameters).await
+    }
 
-        let request = build_request(parameters);
+    // Klines of a contract type (perpetual, current or next quarter) of a pair, e.g. "BTCUSDT"
+    pub async fn get_continuous_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, contract_type: ContractType, interval: S2, limit: S3, start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
 
-        let data: Vec<Vec<Value>> = self
-            .client
-            .get(API::Futures(Futures::Klines), Some(request))?;
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("contractType".into(), contract_type.into());
+        parameters.insert("interval".into(), interval.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        self.klines(API::Futures(Futures::ContinuousKlines), parameters)
+            .await
+    }
+
+    // Index price klines of a pair. The volume and trade count columns are not used (zero).
+    pub async fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("interval".into(), interval.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        self.klines(API::Futures(Futures::IndexPriceKlines), parameters)
+            .await
+    }
+
+    // Mark price klines of a symbol. The volume and trade count columns are not used (zero).
+    pub async fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        self.klines(API::Futures(Futures::MarkPriceKlines), parameters)
+            .await
+    }
+
+    // Premium index klines of a symbol. The volume and trade count columns are not used (zero).
+    pub async fn get_premium_index_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        self.klines(API::Futures(Futures::PremiumIndexKlines), parameters)
+            .await
+    }
+
+    async fn klines(
+        &self, endpoint: API, parameters: BTreeMap<String, String>,
+    ) -> Result<KlineSummaries> {
+        let request = build_request(parameters);
+        let data: Vec<Vec<Value>> = self.client.get(endpoint, Some(request)).await?;
 
         let klines = KlineSummaries::AllKlineSummaries(
             data.iter()
@@ -186,7 +313,7 @@ impl FuturesMarket {
     }
 
     // 24hr ticker price change statistics
-    pub fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats>
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats>
     where
         S: Into<String>,
     {
@@ -197,15 +324,18 @@ impl FuturesMarket {
 
         self.client
             .get(API::Futures(Futures::Ticker24hr), Some(request))
+            .await
     }
 
     // 24hr ticker price change statistics for all symbols
-    pub fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
-        self.client.get(API::Futures(Futures::Ticker24hr), None)
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.client
+            .get(API::Futures(Futures::Ticker24hr), None)
+            .await
     }
 
     // Latest price for ONE symbol.
-    pub fn get_price<S>(&self, symbol: S) -> Result<SymbolPrice>
+    pub async fn get_price<S>(&self, symbol: S) -> Result<SymbolPrice>
     where
         S: Into<String>,
     {
@@ -216,21 +346,26 @@ impl FuturesMarket {
 
         self.client
             .get(API::Futures(Futures::TickerPrice), Some(request))
+            .await
     }
 
     // Latest price for all symbols.
-    pub fn get_all_prices(&self) -> Result<crate::model::Prices> {
-        self.client.get(API::Futures(Futures::TickerPrice), None)
+    pub async fn get_all_prices(&self) -> Result<crate::model::Prices> {
+        self.client
+            .get(API::Futures(Futures::TickerPrice), None)
+            .await
     }
 
     // Symbols order book ticker
     // -> Best price/qty on the order book for ALL symbols.
-    pub fn get_all_book_tickers(&self) -> Result<BookTickers> {
-        self.client.get(API::Futures(Futures::BookTicker), None)
+    pub async fn get_all_book_tickers(&self) -> Result<BookTickers> {
+        self.client
+            .get(API::Futures(Futures::BookTicker), None)
+            .await
     }
 
     // -> Best price/qty on the order book for ONE symbol
-    pub fn get_book_ticker<S>(&self, symbol: S) -> Result<Tickers>
+    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<Tickers>
     where
         S: Into<String>,
     {
@@ -239,17 +374,69 @@ impl FuturesMarket {
         let request = build_request(parameters);
         self.client
             .get(API::Futures(Futures::BookTicker), Some(request))
+            .await
     }
 
-    pub fn get_mark_prices(&self) -> Result<MarkPrices> {
-        self.client.get(API::Futures(Futures::PremiumIndex), None)
+    pub async fn get_mark_prices(&self) -> Result<MarkPrices> {
+        self.client
+            .get(API::Futures(Futures::PremiumIndex), None)
+            .await
     }
 
-    pub fn get_all_liquidation_orders(&self) -> Result<LiquidationOrders> {
-        self.client.get(API::Futures(Futures::AllForceOrders), None)
+    // Mark price, index price and funding rate for ONE symbol
+    pub async fn get_mark_price<S>(&self, symbol: S) -> Result<MarkPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::PremiumIndex), Some(request))
+            .await
     }
 
-    pub fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
+    // Funding rates of ONE symbol, in ascending order. Without start_time and end_time,
+    // the most recent 'limit' (default 100, max 1000) are returned.
+    pub async fn get_funding_rate_history<S, F, N>(
+        &self, symbol: S, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::FundingRate), Some(request))
+            .await
+    }
+
+    // Funding rate caps and interval hours of the symbols whose funding was adjusted
+    pub async fn get_funding_info(&self) -> Result<Vec<FundingInfo>> {
+        self.client
+            .get(API::Futures(Futures::FundingInfo), None)
+            .await
+    }
+
+    pub async fn get_all_liquidation_orders(&self) -> Result<LiquidationOrders> {
+        self.client
+            .get(API::Futures(Futures::AllForceOrders), None)
+            .await
+    }
+
+    pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
     where
         S: Into<String>,
     {
@@ -258,9 +445,10 @@ impl FuturesMarket {
         let request = build_request(parameters);
         self.client
             .get(API::Futures(Futures::OpenInterest), Some(request))
+            .await
     }
 
-    pub fn open_interest_statistics<S1, S2, S3, S4, S5>(
+    pub async fn open_interest_statistics<S1, S2, S3, S4, S5>(
         &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
     ) -> Result<Vec<OpenInterestHist>>
     where
@@ -287,5 +475,170 @@ impl FuturesMarket {
         let request = build_request(parameters);
         self.client
             .get(API::Futures(Futures::OpenInterestHist), Some(request))
+            .await
+    }
+
+    // Long/short ratio of the accounts of the top traders (by margin balance)
+    // period: "5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h" or "1d", over the last 30 days
+    pub async fn top_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("period".into(), period.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        let request = build_request(parameters);
+        self.client
+            .get(
+                API::Futures(Futures::TopLongShortAccountRatio),
+                Some(request),
+            )
+            .await
+    }
+
+    // Long/short ratio of the positions of the top traders (by margin balance)
+    // period: "5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h" or "1d", over the last 30 days
+    pub async fn top_long_short_position_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("period".into(), period.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        let request = build_request(parameters);
+        self.client
+            .get(
+                API::Futures(Futures::TopLongShortPositionRatio),
+                Some(request),
+            )
+            .await
+    }
+
+    // Long/short ratio of all the accounts
+    // period: "5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h" or "1d", over the last 30 days
+    pub async fn global_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("period".into(), period.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        let request = build_request(parameters);
+        self.client
+            .get(
+                API::Futures(Futures::GlobalLongShortAccountRatio),
+                Some(request),
+            )
+            .await
+    }
+
+    // Taker buy and sell volumes
+    // period: "5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h" or "1d", over the last 30 days
+    pub async fn taker_buy_sell_volume<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<TakerBuySellVolume>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("period".into(), period.into());
+        add_time_range(
+            &mut parameters,
+            limit.into(),
+            start_time.into(),
+            end_time.into(),
+        );
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::TakerlongshortRatio), Some(request))
+            .await
+    }
+
+    // Basis of a contract type of a pair
+    // period: "5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h" or "1d", over the last 30 days
+    pub async fn basis<S1, S2, S3, S4, S5>(
+        &self, pair: S1, contract_type: ContractType, period: S2, limit: S3, start_time: S4,
+        end_time: S5,
+    ) -> Result<Vec<Basis>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("contractType".into(), contract_type.into());
+        parameters.insert("period".into(), period.into());
+        // limit is required by this endpoint, 30 is the documented default
+        parameters.insert("limit".into(), limit.into().unwrap_or(30).to_string());
+        add_time_range(&mut parameters, None, start_time.into(), end_time.into());
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::Basis), Some(request))
+            .await
+    }
+}
+
+// The optional limit, startTime and endTime of the kline and statistics endpoints
+pub(crate) fn add_time_range(
+    parameters: &mut BTreeMap<String, String>, limit: Option<u16>, start_time: Option<u64>,
+    end_time: Option<u64>,
+) {
+    if let Some(lt) = limit {
+        parameters.insert("limit".into(), format!("{}", lt));
+    }
+    if let Some(st) = start_time {
+        parameters.insert("startTime".into(), format!("{}", st));
+    }
+    if let Some(et) = end_time {
+        parameters.insert("endTime".into(), format!("{}", et));
     }
 }
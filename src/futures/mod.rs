@@ -1,6 +1,8 @@
 pub mod account;
+pub mod coinm;
 pub mod general;
 pub mod market;
 pub mod model;
+pub mod registry;
 pub mod userstream;
 pub mod websockets;
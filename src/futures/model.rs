@@ -1,9 +1,9 @@
 use serde::{Deserialize, Serialize};
-use crate::model::{string_or_float, string_or_float_opt, string_or_bool};
+use crate::model::{string_or_float, string_or_float_opt, string_or_bool, Number, NumberString};
 
 pub use crate::model::{
-    Asks, Bids, BookTickers, Filters, KlineSummaries, KlineSummary, RateLimit, ServerTime,
-    SymbolPrice, Tickers,
+    Asks, Bids, BookTickers, ExchangeFilter, Filters, KlineSummaries, KlineSummary, RateLimit,
+    ServerTime, SymbolPrice, Tickers,
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -12,24 +12,56 @@ pub struct ExchangeInformation {
     pub timezone: String,
     pub server_time: u64,
     pub rate_limits: Vec<RateLimit>,
-    pub exchange_filters: Vec<String>,
+    pub exchange_filters: Vec<ExchangeFilter>,
+    #[serde(default)]
+    pub assets: Vec<ExchangeAsset>,
     pub symbols: Vec<Symbol>,
 }
 
+// Asset that can be used as margin in the Multi-Assets mode
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeAsset {
+    pub asset: String,
+    pub margin_available: bool,
+    // threshold of the auto asset exchange, none for non margin assets
+    #[serde(default, with = "string_or_float_opt")]
+    pub auto_asset_exchange: Option<Number>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Symbol {
     pub symbol: String,
+    pub pair: String,
+    // PERPETUAL, CURRENT_MONTH, NEXT_MONTH, CURRENT_QUARTER, NEXT_QUARTER, ...
+    pub contract_type: String,
+    // 4133404800000 for perpetual contracts
+    pub delivery_date: u64,
+    pub onboard_date: u128,
     pub status: String,
     pub maint_margin_percent: String,
     pub required_margin_percent: String,
     pub base_asset: String,
     pub quote_asset: String,
-    pub onboard_date: u128,
+    pub margin_asset: String,
     pub price_precision: u16,
     pub quantity_precision: u16,
     pub base_asset_precision: u64,
     pub quote_precision: u64,
+    // COIN, INDEX, ...
+    #[serde(default)]
+    pub underlying_type: String,
+    #[serde(default)]
+    pub underlying_sub_type: Vec<String>,
+    // price change that triggers the protection of stop orders
+    #[serde(default, with = "string_or_float_opt")]
+    pub trigger_protect: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub liquidation_fee: Option<Number>,
+    // maximum deviation of the price of market orders from the mark price
+    #[serde(default, with = "string_or_float_opt")]
+    pub market_take_bound: Option<Number>,
     pub filters: Vec<Filters>,
     pub order_types: Vec<String>,
     pub time_in_force: Vec<String>,
@@ -53,23 +85,23 @@ pub struct OrderBook {
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
     pub symbol: String,
-    pub price_change: String,
+    pub price_change: NumberString,
     pub price_change_percent: String,
-    pub weighted_avg_price: String,
+    pub weighted_avg_price: NumberString,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
     #[serde(with = "string_or_float")]
-    pub quote_volume: f64,
+    pub quote_volume: Number,
     #[serde(with = "string_or_float")]
-    pub last_qty: f64,
+    pub last_qty: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -82,19 +114,19 @@ pub struct PriceStats {
 pub struct TradeHistory {
     pub buyer: bool,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
     pub id: u64,
     pub maker: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Number,
     #[serde(with = "string_or_float")]
-    pub realized_pnl: f64,
+    pub realized_pnl: Number,
     pub side: String,
     pub position_side: String,
     pub symbol: String,
@@ -113,11 +145,11 @@ pub struct Trade {
     pub id: u64,
     pub is_buyer_maker: bool,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Number,
     pub time: u64,
 }
 
@@ -141,9 +173,9 @@ pub struct AggTrade {
     #[serde(rename = "m")]
     pub maker: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -157,13 +189,45 @@ pub enum MarkPrices {
 pub struct MarkPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub index_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub estimated_settle_price: Option<Number>,
     #[serde(with = "string_or_float")]
-    pub last_funding_rate: f64,
+    pub last_funding_rate: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub interest_rate: Option<Number>,
     pub next_funding_time: u64,
     pub time: u64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub funding_rate: Number,
+    pub funding_time: u64,
+    // empty for the oldest funding rates
+    #[serde(default, with = "string_or_float_opt")]
+    pub mark_price: Option<Number>,
+}
+
+// Funding rate cap/floor and interval of a symbol whose funding was adjusted
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingInfo {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub adjusted_funding_rate_cap: Number,
+    #[serde(with = "string_or_float")]
+    pub adjusted_funding_rate_floor: Number,
+    pub funding_interval_hours: u64,
+    #[serde(default)]
+    pub disclaimer: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum LiquidationOrders {
@@ -174,13 +238,13 @@ pub enum LiquidationOrders {
 #[serde(rename_all = "camelCase")]
 pub struct LiquidationOrder {
     #[serde(with = "string_or_float")]
-    pub average_price: f64,
+    pub average_price: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub side: String,
     pub status: String,
     pub symbol: String,
@@ -193,7 +257,7 @@ pub struct LiquidationOrder {
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterest {
     #[serde(with = "string_or_float")]
-    pub open_interest: f64,
+    pub open_interest: Number,
     pub symbol: String,
 }
 
@@ -201,8 +265,55 @@ pub struct OpenInterest {
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterestHist {
     pub symbol: String,
-    pub sum_open_interest: String,
-    pub sum_open_interest_value: String,
+    pub sum_open_interest: NumberString,
+    pub sum_open_interest_value: NumberString,
+    pub timestamp: u64,
+}
+
+// Ratio of long to short accounts or positions, over a period
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub long_short_ratio: Number,
+    #[serde(with = "string_or_float")]
+    pub long_account: Number,
+    #[serde(with = "string_or_float")]
+    pub short_account: Number,
+    pub timestamp: u64,
+}
+
+// Taker buy and sell volumes, over a period
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolume {
+    #[serde(with = "string_or_float")]
+    pub buy_sell_ratio: Number,
+    #[serde(with = "string_or_float")]
+    pub buy_vol: Number,
+    #[serde(with = "string_or_float")]
+    pub sell_vol: Number,
+    pub timestamp: u64,
+}
+
+// Difference between the futures price and the index price of a pair, over a period
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Basis {
+    pub pair: String,
+    pub contract_type: String,
+    #[serde(with = "string_or_float")]
+    pub futures_price: Number,
+    #[serde(with = "string_or_float")]
+    pub index_price: Number,
+    #[serde(with = "string_or_float")]
+    pub basis: Number,
+    #[serde(with = "string_or_float")]
+    pub basis_rate: Number,
+    // empty for perpetual contracts
+    #[serde(default, with = "string_or_float_opt")]
+    pub annualized_basis_rate: Option<Number>,
     pub timestamp: u64,
 }
 
@@ -211,24 +322,24 @@ pub struct OpenInterestHist {
 pub struct Order {
     pub client_order_id: String,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub side: String,
     pub reduce_only: bool,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -236,9 +347,9 @@ pub struct Order {
     pub order_type: String,
     pub orig_type: String,
     #[serde(with = "string_or_float", default = "default_activation_price")]
-    pub activation_price: f64,
+    pub activation_price: Number,
     #[serde(with = "string_or_float", default = "default_price_rate")]
-    pub price_rate: f64,
+    pub price_rate: Number,
     pub update_time: u64,
     pub working_type: String,
     pub price_protect: bool,
@@ -249,22 +360,22 @@ pub struct Order {
 pub struct Transaction {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -273,10 +384,10 @@ pub struct Transaction {
     pub orig_type: String,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
@@ -287,23 +398,23 @@ pub struct Transaction {
 pub struct CanceledOrder {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     pub orig_type: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -311,10 +422,10 @@ pub struct CanceledOrder {
     pub type_name: String,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
@@ -324,29 +435,29 @@ pub struct CanceledOrder {
 #[serde(rename_all = "camelCase")]
 pub struct PositionRisk {
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
     pub margin_type: String,
     #[serde(with = "string_or_bool")]
     pub is_auto_add_margin: bool,
     #[serde(with = "string_or_float")]
-    pub isolated_margin: f64,
+    pub isolated_margin: Number,
     pub leverage: String,
     #[serde(with = "string_or_float")]
-    pub liquidation_price: f64,
+    pub liquidation_price: Number,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     pub symbol: String,
     #[serde(with = "string_or_float", rename = "unRealizedProfit")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     pub position_side: String,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Number,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
     pub update_time: u64,
 }
 
@@ -355,27 +466,27 @@ pub struct PositionRisk {
 pub struct FuturesAsset {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub margin_balance: f64,
+    pub margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub cross_un_pnl: f64,
+    pub cross_un_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_bool")]
     pub margin_available: bool,
     pub update_time: u64,
@@ -386,34 +497,34 @@ pub struct FuturesAsset {
 pub struct FuturesPosition {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     pub leverage: String,
     #[serde(with = "string_or_bool")]
     pub isolated: bool,
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
     #[serde(with = "string_or_float")]
-    pub max_notional: f64,
+    pub max_notional: Number,
     pub position_side: String,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Number,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
     pub update_time: u64,
     #[serde(with = "string_or_float")]
-    pub bid_notional: f64,
+    pub bid_notional: Number,
     #[serde(with = "string_or_float")]
-    pub ask_notional: f64,
+    pub ask_notional: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -430,27 +541,27 @@ pub struct AccountInformation {
     #[serde(with = "string_or_float")]
     pub update_time: f64,
     #[serde(with = "string_or_float")]
-    pub total_initial_margin: f64,
+    pub total_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_maint_margin: f64,
+    pub total_maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_wallet_balance: f64,
+    pub total_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_unrealized_profit: f64,
+    pub total_unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub total_margin_balance: f64,
+    pub total_margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_position_initial_margin: f64,
+    pub total_position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_open_order_initial_margin: f64,
+    pub total_open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_cross_wallet_balance: f64,
+    pub total_cross_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_cross_un_pnl: f64,
+    pub total_cross_un_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub assets: Vec<FuturesAsset>,
     pub positions: Vec<FuturesPosition>,
 }
@@ -461,15 +572,15 @@ pub struct AccountBalance {
     pub account_alias: String,
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub balance: f64,
+    pub balance: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float", rename = "crossUnPnl")]
-    pub cross_unrealized_pnl: f64,
+    pub cross_unrealized_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub margin_available: bool,
     pub update_time: u64,
 }
@@ -479,18 +590,18 @@ pub struct AccountBalance {
 pub struct ChangeLeverageResponse {
     pub leverage: u8,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
     pub symbol: String,
 }
 
-fn default_stop_price() -> f64 {
-    0.0
+fn default_stop_price() -> Number {
+    Number::default()
 }
-fn default_activation_price() -> f64 {
-    0.0
+fn default_activation_price() -> Number {
+    Number::default()
 }
-fn default_price_rate() -> f64 {
-    0.0
+fn default_price_rate() -> Number {
+    Number::default()
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -512,16 +623,16 @@ pub struct OrderUpdate {
     pub time_in_force: String,
 
     #[serde(rename = "q")]
-    pub qty: String,
+    pub qty: NumberString,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(rename = "ap")]
-    pub average_price: String,
+    pub average_price: NumberString,
 
     #[serde(rename = "sp")]
-    pub stop_price: String,
+    pub stop_price: NumberString,
 
     #[serde(rename = "x")]
     pub execution_type: String,
@@ -533,19 +644,19 @@ pub struct OrderUpdate {
     pub order_id: u64,
 
     #[serde(rename = "l")]
-    pub qty_last_filled_trade: String,
+    pub qty_last_filled_trade: NumberString,
 
     #[serde(rename = "z")]
-    pub accumulated_qty_filled_trades: String,
+    pub accumulated_qty_filled_trades: NumberString,
 
     #[serde(rename = "L")]
-    pub price_last_filled_trade: String,
+    pub price_last_filled_trade: NumberString,
 
     #[serde(skip, rename = "N")]
     pub asset_commisioned: Option<String>,
 
     #[serde(rename = "n")]
-    pub commission: Option<String>,
+    pub commission: Option<NumberString>,
 
     #[serde(rename = "T")]
     pub trade_order_time: u64,
@@ -554,10 +665,10 @@ pub struct OrderUpdate {
     pub trade_id: i64,
 
     #[serde(rename = "b")]
-    pub bids_notional: String,
+    pub bids_notional: NumberString,
 
     #[serde(rename = "a")]
-    pub ask_notional: String,
+    pub ask_notional: NumberString,
 
     #[serde(rename = "m")]
     pub is_buyer_maker: bool,
@@ -578,7 +689,7 @@ pub struct OrderUpdate {
     pub close_all: Option<bool>,
 
     #[serde(rename = "AP")]
-    pub activation_price: Option<String>,
+    pub activation_price: Option<NumberString>,
 
     #[serde(rename = "cr")]
     pub callback_rate: Option<String>,
@@ -593,7 +704,7 @@ pub struct OrderUpdate {
     pub ss_ignore: i32,
 
     #[serde(rename = "rp")]
-    pub realized_profit: String,
+    pub realized_profit: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -618,7 +729,7 @@ pub struct Income {
     pub symbol: String,
     pub income_type: String,
     #[serde(with = "string_or_float")]
-    pub income: f64,
+    pub income: Number,
     pub asset: String,
     pub info: String,
     pub time: u64,
@@ -0,0 +1,122 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use error_chain::bail;
+
+use crate::errors::Result;
+use crate::futures::account::ContractType;
+use crate::futures::general::FuturesGeneral;
+#[cfg(feature = "async")]
+use crate::futures::general::AsyncFuturesGeneral;
+use crate::futures::model::{ExchangeInformation, Symbol};
+
+struct Cached {
+    fetched_at: Instant,
+    info: Arc<ExchangeInformation>,
+}
+
+/// Symbols of the futures exchange information, cached for `ttl`.
+///
+/// The exchange information is fetched with `FuturesGeneral::exchange_info` on the first
+/// lookup, and again on the first lookup after it has expired. Clones share the cache.
+#[maybe_async_cfg::maybe(
+    idents(FuturesGeneral(sync, async = "AsyncFuturesGeneral")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncSymbolRegistry")
+)]
+#[derive(Clone)]
+pub struct SymbolRegistry {
+    general: FuturesGeneral,
+    ttl: Duration,
+    cache: Arc<Mutex<Option<Cached>>>,
+}
+
+#[maybe_async_cfg::maybe(
+    idents(FuturesGeneral(sync, async = "AsyncFuturesGeneral")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncSymbolRegistry")
+)]
+impl SymbolRegistry {
+    pub fn new(general: FuturesGeneral, ttl: Duration) -> Self {
+        Self {
+            general,
+            ttl,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Exchange information, fetched again if older than the TTL.
+    pub async fn exchange_info(&self) -> Result<Arc<ExchangeInformation>> {
+        let cached = self
+            .cache()
+            .as_ref()
+            .filter(|cached| cached.fetched_at.elapsed() < self.ttl)
+            .map(|cached| cached.info.clone());
+        match cached {
+            Some(info) => Ok(info),
+            None => self.refresh().await,
+        }
+    }
+
+    /// Fetch the exchange information now, whatever its age.
+    pub async fn refresh(&self) -> Result<Arc<ExchangeInformation>> {
+        let info = Arc::new(self.general.exchange_info().await?);
+        *self.cache() = Some(Cached {
+            fetched_at: Instant::now(),
+            info: info.clone(),
+        });
+        Ok(info)
+    }
+
+    /// Drop the cached exchange information, the next lookup fetches it again.
+    pub fn invalidate(&self) {
+        *self.cache() = None;
+    }
+
+    /// Symbol by name, e.g. "BTCUSDT" or "BTCUSDT_250627".
+    pub async fn symbol(&self, symbol: &str) -> Result<Symbol> {
+        let symbol = symbol.to_uppercase();
+        let info = self.exchange_info().await?;
+        match info.symbols.iter().find(|item| item.symbol == symbol) {
+            Some(item) => Ok(item.clone()),
+            None => bail!("Symbol not found"),
+        }
+    }
+
+    /// Perpetual and delivery contracts of a pair, e.g. "BTCUSDT".
+    pub async fn symbols_by_pair(&self, pair: &str) -> Result<Vec<Symbol>> {
+        let pair = pair.to_uppercase();
+        self.filter(|item| item.pair == pair).await
+    }
+
+    pub async fn symbols_by_contract_type(
+        &self, contract_type: ContractType,
+    ) -> Result<Vec<Symbol>> {
+        let contract_type = String::from(contract_type);
+        self.filter(|item| item.contract_type == contract_type)
+            .await
+    }
+
+    /// Symbols margined in an asset, e.g. "USDT" or "USDC".
+    pub async fn symbols_by_margin_asset(&self, margin_asset: &str) -> Result<Vec<Symbol>> {
+        let margin_asset = margin_asset.to_uppercase();
+        self.filter(|item| item.margin_asset == margin_asset).await
+    }
+
+    async fn filter<P>(&self, predicate: P) -> Result<Vec<Symbol>>
+    where
+        P: Fn(&Symbol) -> bool,
+    {
+        let info = self.exchange_info().await?;
+        Ok(info
+            .symbols
+            .iter()
+            .filter(|item| predicate(item))
+            .cloned()
+            .collect())
+    }
+
+    fn cache(&self) -> MutexGuard<'_, Option<Cached>> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
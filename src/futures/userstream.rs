@@ -1,28 +1,44 @@
 use crate::model::{Success, UserDataStream};
 use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
 use crate::errors::Result;
 use crate::api::API;
 use crate::api::Futures;
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncFuturesUserStream")
+)]
 #[derive(Clone)]
 pub struct FuturesUserStream {
     pub client: Client,
     pub recv_window: u64,
 }
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncFuturesUserStream")
+)]
 impl FuturesUserStream {
     // User Stream
-    pub fn start(&self) -> Result<UserDataStream> {
-        self.client.post(API::Futures(Futures::UserDataStream))
+    pub async fn start(&self) -> Result<UserDataStream> {
+        self.client
+            .post(API::Futures(Futures::UserDataStream))
+            .await
     }
 
-    pub fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+    pub async fn keep_alive(&self, listen_key: &str) -> Result<Success> {
         self.client
             .put(API::Futures(Futures::UserDataStream), listen_key)
+            .await
     }
 
-    pub fn close(&self, listen_key: &str) -> Result<Success> {
+    pub async fn close(&self, listen_key: &str) -> Result<Success> {
         self.client
             .delete(API::Futures(Futures::UserDataStream), listen_key)
+            .await
     }
 }
@@ -1,5 +1,8 @@
 use crate::errors::Result;
-use crate::config::Config;
+use crate::cassette::{normalize_url, Cassette, CassetteMode};
+use crate::config::{Config, ConnectionConfig};
+use crate::connection::connect_websocket;
+use crate::util::redact_url;
 use crate::model::{
     AccountUpdateEvent, AggrTradesEvent, BookTickerEvent, ContinuousKlineEvent, DayTickerEvent,
     DepthOrderBookEvent, IndexKlineEvent, IndexPriceEvent, KlineEvent, LiquidationEvent,
@@ -9,9 +12,11 @@ use crate::futures::model;
 use error_chain::bail;
 use url::Url;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::net::TcpStream;
-use tungstenite::{connect, Message};
+use tungstenite::Message;
 use tungstenite::protocol::WebSocket;
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::handshake::client::Response;
@@ -82,8 +87,13 @@ pub enum FuturesWebsocketEvent {
 pub struct FuturesWebSockets<'a> {
     pub socket: Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)>,
     handler: Box<dyn FnMut(FuturesWebsocketEvent) -> Result<()> + 'a>,
+    cassette: Option<Arc<Cassette>>,
+    stream: String,
+    replay: Option<VecDeque<String>>,
+    connection: ConnectionConfig,
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 enum FuturesEvents {
@@ -116,9 +126,18 @@ impl<'a> FuturesWebSockets<'a> {
         FuturesWebSockets {
             socket: None,
             handler: Box::new(handler),
+            cassette: None,
+            stream: String::new(),
+            replay: None,
+            connection: ConnectionConfig::default(),
         }
     }
 
+    /// Record the frames received to `cassette`, or replay them from there.
+    pub fn set_cassette(&mut self, cassette: Arc<Cassette>) {
+        self.cassette = Some(cassette);
+    }
+
     pub fn connect(&mut self, market: &FuturesMarket, subscription: &'a str) -> Result<()> {
         self.connect_wss(&FuturesWebsocketAPI::Default.params(market, subscription))
     }
@@ -126,6 +145,10 @@ impl<'a> FuturesWebSockets<'a> {
     pub fn connect_with_config(
         &mut self, market: &FuturesMarket, subscription: &'a str, config: &'a Config,
     ) -> Result<()> {
+        if let Some(cassette) = &config.cassette {
+            self.set_cassette(cassette.clone());
+        }
+        self.connection = config.connection.clone();
         self.connect_wss(
             &FuturesWebsocketAPI::Custom(config.ws_endpoint.clone()).params(market, subscription),
         )
@@ -138,19 +161,40 @@ impl<'a> FuturesWebSockets<'a> {
     }
 
     fn connect_wss(&mut self, wss: &str) -> Result<()> {
+        self.stream = normalize_url(wss)?;
+        if let Some(cassette) = &self.cassette {
+            if cassette.mode() == CassetteMode::Replay {
+                self.replay = Some(cassette.replay_frames(&self.stream).into());
+                tracing::info!(stream = %redact_url(&self.stream), "replaying websocket stream");
+                return Ok(());
+            }
+        }
+
         let url = Url::parse(wss)?;
-        match connect(url) {
+        match connect_websocket(&url, &self.connection) {
             Ok(answer) => {
+                tracing::info!(stream = %redact_url(&self.stream), "websocket connected");
                 self.socket = Some(answer);
                 Ok(())
             }
-            Err(e) => bail!(format!("Error during handshake {}", e)),
+            Err(e) => {
+                tracing::warn!(
+                    stream = %redact_url(&self.stream),
+                    error = %e,
+                    "websocket handshake failed"
+                );
+                bail!(format!("Error during handshake {}", e))
+            }
         }
     }
 
     pub fn disconnect(&mut self) -> Result<()> {
+        if self.replay.take().is_some() {
+            return Ok(());
+        }
         if let Some(ref mut socket) = self.socket {
             socket.0.close(None)?;
+            tracing::info!(stream = %redact_url(&self.stream), "websocket disconnected");
             return Ok(());
         }
         bail!("Not able to close the connection");
@@ -161,7 +205,17 @@ impl<'a> FuturesWebSockets<'a> {
     }
 
     pub fn handle_msg(&mut self, msg: &str) -> Result<()> {
-        let value: serde_json::Value = serde_json::from_str(msg)?;
+        let value: serde_json::Value = match serde_json::from_str(msg) {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::warn!(
+                    stream = %redact_url(&self.stream),
+                    error = %e,
+                    "invalid websocket message"
+                );
+                return Err(e.into());
+            }
+        };
 
         if let Some(data) = value.get("data") {
             self.handle_msg(&data.to_string())?;
@@ -193,6 +247,8 @@ impl<'a> FuturesWebSockets<'a> {
                 }
             };
             (self.handler)(action)?;
+        } else {
+            tracing::debug!(stream = %redact_url(&self.stream), "unhandled websocket message");
         }
         Ok(())
     }
@@ -200,18 +256,42 @@ impl<'a> FuturesWebSockets<'a> {
     pub fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
         while running.load(Ordering::Relaxed) {
             if let Some(ref mut socket) = self.socket {
-                let message = socket.0.read_message()?;
+                let message = socket.0.read()?;
                 match message {
                     Message::Text(msg) => {
+                        if let Some(cassette) = &self.cassette {
+                            cassette.record_frame(&self.stream, &msg)?;
+                        }
                         if let Err(e) = self.handle_msg(&msg) {
+                            tracing::warn!(
+                                stream = %redact_url(&self.stream),
+                                error = %e,
+                                "failed to handle websocket message"
+                            );
                             bail!(format!("Error on handling stream message: {}", e));
                         }
                     }
                     Message::Ping(payload) => {
-                        socket.0.write_message(Message::Pong(payload)).unwrap();
+                        tracing::trace!(stream = %redact_url(&self.stream), "websocket ping");
+                        socket.0.send(Message::Pong(payload)).unwrap();
                     }
                     Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => (),
-                    Message::Close(e) => bail!(format!("Disconnected {:?}", e)),
+                    Message::Close(e) => {
+                        tracing::info!(
+                            stream = %redact_url(&self.stream),
+                            frame = ?e,
+                            "websocket closed by server"
+                        );
+                        bail!(format!("Disconnected {:?}", e))
+                    }
+                }
+            } else if let Some(replay) = self.replay.as_mut() {
+                // a replayed stream ends with its last recorded frame
+                let Some(msg) = replay.pop_front() else {
+                    return Ok(());
+                };
+                if let Err(e) = self.handle_msg(&msg) {
+                    bail!(format!("Error on handling stream message: {}", e));
                 }
             }
         }
@@ -6,7 +6,7 @@
     unused_import_braces,
     clippy::all
 )]
-#![allow(clippy::needless_doctest_main)]
+#![allow(clippy::needless_doctest_main, clippy::result_large_err)]
 #![warn(
     clippy::wildcard_imports,
     clippy::manual_string_new,
@@ -16,8 +16,16 @@
 )]
 
 mod client;
+mod connection;
+pub mod cassette;
+pub mod clock;
 pub mod errors;
+pub mod rate_limit;
+pub mod retry;
+pub mod signer;
+pub mod transport;
 pub mod util;
+pub mod validator;
 
 pub mod model;
 
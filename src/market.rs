@@ -1,15 +1,39 @@
-use crate::util::build_request;
+use crate::util::{build_request, build_symbols_param};
 use crate::model::{
     AggTrade, AveragePrice, BookTickers, KlineSummaries, KlineSummary, OrderBook, PriceStats,
-    Prices, SymbolPrice, Tickers,
+    Prices, SymbolPrice, TickerStats, Tickers, Trade,
 };
 use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
 use crate::errors::Result;
 use std::collections::BTreeMap;
+use std::fmt::Display;
 use serde_json::Value;
 use crate::api::API;
 use crate::api::Spot;
 
+/// Statistics returned by the ticker endpoints: FULL (the default) or MINI, which
+/// leaves out the price change, bid/ask and last trade fields.
+pub enum TickerType {
+    Full,
+    Mini,
+}
+
+impl Display for TickerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "FULL"),
+            Self::Mini => write!(f, "MINI"),
+        }
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncMarket")
+)]
 #[derive(Clone)]
 pub struct Market {
     pub client: Client,
@@ -17,21 +41,26 @@ pub struct Market {
 }
 
 // Market Data endpoints
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncMarket")
+)]
 impl Market {
     // Order book at the default depth of 100
-    pub fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
     where
         S: Into<String>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
-        self.client.get(API::Spot(Spot::Depth), Some(request))
+        self.client.get(API::Spot(Spot::Depth), Some(request)).await
     }
 
     // Order book at a custom depth. Currently supported values
     // are 5, 10, 20, 50, 100, 500, 1000 and 5000
-    pub fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
+    pub async fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
     where
         S: Into<String>,
     {
@@ -39,74 +68,210 @@ impl Market {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("limit".into(), depth.to_string());
         let request = build_request(parameters);
-        self.client.get(API::Spot(Spot::Depth), Some(request))
+        self.client.get(API::Spot(Spot::Depth), Some(request)).await
     }
 
     // Latest price for ALL symbols.
-    pub fn get_all_prices(&self) -> Result<Prices> {
-        self.client.get(API::Spot(Spot::Price), None)
+    pub async fn get_all_prices(&self) -> Result<Prices> {
+        self.client.get(API::Spot(Spot::Price), None).await
     }
 
     // Latest price for ONE symbol.
-    pub fn get_price<S>(&self, symbol: S) -> Result<SymbolPrice>
+    pub async fn get_price<S>(&self, symbol: S) -> Result<SymbolPrice>
     where
         S: Into<String>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
-        self.client.get(API::Spot(Spot::Price), Some(request))
+        self.client.get(API::Spot(Spot::Price), Some(request)).await
     }
 
     // Average price for ONE symbol.
-    pub fn get_average_price<S>(&self, symbol: S) -> Result<AveragePrice>
+    pub async fn get_average_price<S>(&self, symbol: S) -> Result<AveragePrice>
     where
         S: Into<String>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
-        self.client.get(API::Spot(Spot::AvgPrice), Some(request))
+        self.client
+            .get(API::Spot(Spot::AvgPrice), Some(request))
+            .await
     }
 
     // Symbols order book ticker
     // -> Best price/qty on the order book for ALL symbols.
-    pub fn get_all_book_tickers(&self) -> Result<BookTickers> {
-        self.client.get(API::Spot(Spot::BookTicker), None)
+    pub async fn get_all_book_tickers(&self) -> Result<BookTickers> {
+        self.client.get(API::Spot(Spot::BookTicker), None).await
     }
 
     // -> Best price/qty on the order book for ONE symbol
-    pub fn get_book_ticker<S>(&self, symbol: S) -> Result<Tickers>
+    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<Tickers>
     where
         S: Into<String>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
-        self.client.get(API::Spot(Spot::BookTicker), Some(request))
+        self.client
+            .get(API::Spot(Spot::BookTicker), Some(request))
+            .await
     }
 
     // 24hr ticker price change statistics
-    pub fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats>
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats>
     where
         S: Into<String>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
-        self.client.get(API::Spot(Spot::Ticker24hr), Some(request))
+        self.client
+            .get(API::Spot(Spot::Ticker24hr), Some(request))
+            .await
     }
 
     // 24hr ticker price change statistics for all symbols
-    pub fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
-        self.client.get(API::Spot(Spot::Ticker24hr), None)
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.client.get(API::Spot(Spot::Ticker24hr), None).await
+    }
+
+    // Recent trades, up to 'limit' (default 500, max 1000)
+    pub async fn get_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::Trades), Some(request))
+            .await
+    }
+
+    // Older trades, starting at trade id 'from_id' (the most recent ones if omitted).
+    // Requires the API key, but no signature.
+    pub async fn get_historical_trades<S1, S2, S3>(
+        &self, symbol: S1, from_id: S2, limit: S3,
+    ) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", fi));
+        }
+        let request = build_request(parameters);
+        self.client
+            .get_with_api_key(API::Spot(Spot::HistoricalTrades), Some(request))
+            .await
+    }
+
+    // Latest price for several symbols, in one request
+    pub async fn get_prices<S>(&self, symbols: &[S]) -> Result<Vec<SymbolPrice>>
+    where
+        S: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        let request = build_request(parameters);
+        self.client.get(API::Spot(Spot::Price), Some(request)).await
+    }
+
+    // Best price/qty on the order book for several symbols, in one request
+    pub async fn get_book_tickers<S>(&self, symbols: &[S]) -> Result<Vec<Tickers>>
+    where
+        S: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::BookTicker), Some(request))
+            .await
+    }
+
+    // 24hr ticker price change statistics for several symbols, in one request
+    pub async fn get_24h_ticker_stats<S, T>(
+        &self, symbols: &[S], ticker_type: T,
+    ) -> Result<Vec<TickerStats>>
+    where
+        S: AsRef<str>,
+        T: Into<Option<TickerType>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        if let Some(ticker_type) = ticker_type.into() {
+            parameters.insert("type".into(), ticker_type.to_string());
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::Ticker24hr), Some(request))
+            .await
+    }
+
+    // Price change statistics over a rolling window ending now.
+    // window_size: "1m".."59m", "1h".."23h" or "1d".."7d" (default "1d")
+    pub async fn get_rolling_window_stats<S, T>(
+        &self, symbols: &[S], window_size: Option<&str>, ticker_type: T,
+    ) -> Result<Vec<TickerStats>>
+    where
+        S: AsRef<str>,
+        T: Into<Option<TickerType>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        if let Some(window_size) = window_size {
+            parameters.insert("windowSize".into(), window_size.into());
+        }
+        if let Some(ticker_type) = ticker_type.into() {
+            parameters.insert("type".into(), ticker_type.to_string());
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::Ticker), Some(request))
+            .await
+    }
+
+    // Price change statistics since the start of the current trading day.
+    // time_zone: hours and minutes from UTC, e.g. "-1:00" or "05:45" (default "0")
+    pub async fn get_trading_day_stats<S, T>(
+        &self, symbols: &[S], time_zone: Option<&str>, ticker_type: T,
+    ) -> Result<Vec<TickerStats>>
+    where
+        S: AsRef<str>,
+        T: Into<Option<TickerType>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbols".into(), build_symbols_param(symbols));
+        if let Some(time_zone) = time_zone {
+            parameters.insert("timeZone".into(), time_zone.into());
+        }
+        if let Some(ticker_type) = ticker_type.into() {
+            parameters.insert("type".into(), ticker_type.to_string());
+        }
+        let request = build_request(parameters);
+        self.client
+            .get(API::Spot(Spot::TickerTradingDay), Some(request))
+            .await
     }
 
     /// Get aggregated historical trades.
     ///
     /// If you provide start_time, you also need to provide end_time.
     /// If from_id, start_time and end_time are omitted, the most recent trades are fetched.
-    pub fn get_agg_trades<S1, S2, S3, S4, S5>(
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
         &self, symbol: S1, from_id: S2, start_time: S3, end_time: S4, limit: S5,
     ) -> Result<Vec<AggTrade>>
     where
@@ -136,12 +301,14 @@ impl Market {
 
         let request = build_request(parameters);
 
-        self.client.get(API::Spot(Spot::AggTrades), Some(request))
+        self.client
+            .get(API::Spot(Spot::AggTrades), Some(request))
+            .await
     }
 
     // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
     // https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data
-    pub fn get_klines<S1, S2, S3, S4, S5>(
+    pub async fn get_klines<S1, S2, S3, S4, S5>(
         &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
     ) -> Result<KlineSummaries>
     where
@@ -167,8 +334,48 @@ impl Market {
             parameters.insert("endTime".into(), format!("{}", et));
         }
 
+        self.klines(API::Spot(Spot::Klines), parameters).await
+    }
+
+    // Klines optimized for presentation, see get_klines.
+    // time_zone: hours and minutes from UTC that the intervals are aligned to, e.g. "-1:00"
+    pub async fn get_ui_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+        time_zone: Option<&str>,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+        if let Some(tz) = time_zone {
+            parameters.insert("timeZone".into(), tz.into());
+        }
+
+        self.klines(API::Spot(Spot::UiKlines), parameters).await
+    }
+
+    async fn klines(
+        &self, endpoint: API, parameters: BTreeMap<String, String>,
+    ) -> Result<KlineSummaries> {
         let request = build_request(parameters);
-        let data: Vec<Vec<Value>> = self.client.get(API::Spot(Spot::Klines), Some(request))?;
+        let data: Vec<Vec<Value>> = self.client.get(endpoint, Some(request)).await?;
 
         let klines = KlineSummaries::AllKlineSummaries(
             data.iter()
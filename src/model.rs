@@ -2,6 +2,32 @@ use serde::{Deserialize, Serialize};
 use serde_json::{from_value, Value};
 use crate::errors::{Error, ErrorKind, Result};
 
+/// Prices, quantities, commissions and balances: `f64`, or `rust_decimal::Decimal`
+/// with the `decimal` feature.
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
+/// Prices, quantities and balances kept as received: `String`, or `rust_decimal::Decimal`
+/// with the `decimal` feature.
+#[cfg(not(feature = "decimal"))]
+pub type NumberString = String;
+#[cfg(feature = "decimal")]
+pub type NumberString = rust_decimal::Decimal;
+
+/// `value` as a `Number`. With the `decimal` feature, the shortest decimal that reads
+/// back as `value`, e.g. `0.1` for `0.1`.
+#[cfg(not(feature = "decimal"))]
+pub fn to_number(value: f64) -> Number {
+    value
+}
+
+#[cfg(feature = "decimal")]
+pub fn to_number(value: f64) -> Number {
+    rust_decimal::Decimal::try_from(value).unwrap_or_default()
+}
+
 #[derive(Deserialize, Clone)]
 pub struct Empty {}
 
@@ -18,6 +44,16 @@ pub struct ExchangeInformation {
     pub server_time: u64,
     pub rate_limits: Vec<RateLimit>,
     pub symbols: Vec<Symbol>,
+    #[serde(default)]
+    pub sors: Vec<SorSymbols>,
+}
+
+// Symbols that SOR orders on `base_asset` can be routed across
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SorSymbols {
+    pub base_asset: String,
+    pub symbols: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -95,6 +131,9 @@ pub enum Filters {
         notional: Option<String>,
         min_notional: Option<String>,
         apply_to_market: Option<bool>,
+        apply_min_to_market: Option<bool>,
+        max_notional: Option<String>,
+        apply_max_to_market: Option<bool>,
         avg_price_mins: Option<f64>,
     },
     #[serde(rename = "ICEBERG_PARTS")]
@@ -135,6 +174,26 @@ pub enum Filters {
     MaxNumOrderAmends { max_num_order_amends: Option<u16> },
 }
 
+// Limits on the number of open orders of an account, across all symbols
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "filterType")]
+pub enum ExchangeFilter {
+    #[serde(rename = "EXCHANGE_MAX_NUM_ORDERS")]
+    #[serde(rename_all = "camelCase")]
+    ExchangeMaxNumOrders { max_num_orders: u64 },
+    #[serde(rename = "EXCHANGE_MAX_NUM_ALGO_ORDERS")]
+    #[serde(rename_all = "camelCase")]
+    ExchangeMaxNumAlgoOrders { max_num_algo_orders: u64 },
+    #[serde(rename = "EXCHANGE_MAX_NUM_ICEBERG_ORDERS")]
+    #[serde(rename_all = "camelCase")]
+    ExchangeMaxNumIcebergOrders { max_num_iceberg_orders: u64 },
+    #[serde(rename = "EXCHANGE_MAX_NUM_ORDER_LISTS")]
+    #[serde(rename_all = "camelCase")]
+    ExchangeMaxNumOrderLists { max_num_order_lists: u64 },
+    #[serde(other)]
+    Other,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountInformation {
@@ -142,18 +201,121 @@ pub struct AccountInformation {
     pub taker_commission: f32,
     pub buyer_commission: f32,
     pub seller_commission: f32,
+    #[serde(default)]
+    pub commission_rates: Option<CommissionRates>,
     pub can_trade: bool,
     pub can_withdraw: bool,
     pub can_deposit: bool,
+    #[serde(default)]
+    pub brokered: bool,
+    #[serde(default)]
+    pub require_self_trade_prevention: bool,
+    #[serde(default)]
+    pub prevent_sor: bool,
+    #[serde(default)]
+    pub update_time: u64,
+    #[serde(default)]
+    pub account_type: String,
     pub balances: Vec<Balance>,
+    #[serde(default)]
+    pub permissions: Vec<String>,
+    #[serde(default)]
+    pub uid: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRates {
+    #[serde(with = "string_or_float")]
+    pub maker: Number,
+    #[serde(with = "string_or_float")]
+    pub taker: Number,
+    #[serde(with = "string_or_float")]
+    pub buyer: Number,
+    #[serde(with = "string_or_float")]
+    pub seller: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCommission {
+    pub symbol: String,
+    pub standard_commission: CommissionRates,
+    #[serde(default)]
+    pub special_commission: Option<CommissionRates>,
+    pub tax_commission: CommissionRates,
+    pub discount: CommissionDiscount,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionDiscount {
+    pub enabled_for_account: bool,
+    pub enabled_for_symbol: bool,
+    pub discount_asset: String,
+    #[serde(with = "string_or_float")]
+    pub discount: Number,
+}
+
+// Unfilled orders counted against the ORDERS rate limits
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UnfilledOrderCount {
+    pub rate_limit_type: String,
+    pub interval: String,
+    pub interval_num: u16,
+    pub limit: u64,
+    pub count: u64,
+}
+
+// An order that expired instead of trading against another order of the same account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PreventedMatch {
+    pub symbol: String,
+    pub prevented_match_id: u64,
+    pub taker_order_id: u64,
+    pub maker_symbol: String,
+    pub maker_order_id: u64,
+    pub trade_group_id: u64,
+    pub self_trade_prevention_mode: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub maker_prevented_quantity: Number,
+    pub transact_time: u64,
+}
+
+// A fill allocated to an order placed through SOR
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Allocation {
+    pub symbol: String,
+    pub allocation_id: u64,
+    pub allocation_type: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub commission: Number,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_allocator: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Balance {
     pub asset: String,
-    pub free: String,
-    pub locked: String,
+    pub free: NumberString,
+    pub locked: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -164,22 +326,22 @@ pub struct Order {
     pub order_list_id: i64,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Number,
+    pub orig_qty: NumberString,
+    pub executed_qty: NumberString,
+    pub cummulative_quote_qty: NumberString,
     pub status: String,
     pub time_in_force: String,
     #[serde(rename = "type")]
     pub type_name: String,
     pub side: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
-    pub iceberg_qty: String,
+    pub stop_price: Number,
+    pub iceberg_qty: NumberString,
     pub time: u64,
     pub update_time: u64,
     pub is_working: bool,
-    pub orig_quote_order_qty: String,
+    pub orig_quote_order_qty: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -190,6 +352,76 @@ pub struct OrderCanceled {
     pub order_id: Option<u64>,
     pub client_order_id: Option<String>,
 }
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceStatus {
+    Success,
+    Failure,
+    NotAttempted,
+}
+
+/// Error of one half of a cancel-replace.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RejectedRequest {
+    pub code: i16,
+    pub msg: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum CancelReplaceResponse<T> {
+    Rejected(RejectedRequest),
+    Accepted(T),
+}
+
+/// Outcome of a cancel-replace, also when one or both halves failed.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCancelReplace {
+    pub cancel_result: CancelReplaceStatus,
+    pub new_order_result: CancelReplaceStatus,
+    pub cancel_response: CancelReplaceResponse<OrderCanceled>,
+    /// `None` when the new order wasn't attempted
+    pub new_order_response: Option<CancelReplaceResponse<Transaction>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub transact_time: u64,
+    pub execution_id: u64,
+    pub amended_order: AmendedOrder,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub prevented_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub quote_order_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub cumulative_quote_qty: Number,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    pub working_time: Option<u64>,
+    pub self_trade_prevention_mode: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum SpotFuturesTransferType {
@@ -213,37 +445,148 @@ pub struct Transaction {
     pub order_list_id: Option<i64>,
     pub client_order_id: String,
     pub transact_time: u64,
+    // the fields below are left out of ACK responses
+    #[serde(with = "string_or_float", default)]
+    pub price: Number,
+    #[serde(with = "string_or_float", default)]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub cummulative_quote_qty: Number,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub stop_price: Number,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub time_in_force: String,
+    #[serde(rename = "type", default)]
+    pub type_name: String,
+    #[serde(default)]
+    pub side: String,
+    pub working_time: Option<u64>,
+    pub self_trade_prevention_mode: Option<String>,
+    pub fills: Option<Vec<FillInfo>>,
+}
+
+fn default_stop_price() -> Number {
+    Number::default()
+}
+
+/// Response to placing an order through Smart Order Routing (SOR).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SorTransaction {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: Option<i64>,
+    pub client_order_id: String,
+    pub transact_time: u64,
+    // the fields below are left out of ACK responses
+    #[serde(with = "string_or_float", default)]
+    pub price: Number,
+    #[serde(with = "string_or_float", default)]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub cummulative_quote_qty: Number,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default)]
+    pub time_in_force: String,
+    #[serde(rename = "type", default)]
+    pub type_name: String,
+    #[serde(default)]
+    pub side: String,
+    pub working_time: Option<u64>,
+    pub working_floor: Option<String>,
+    pub self_trade_prevention_mode: Option<String>,
+    #[serde(default)]
+    pub used_sor: bool,
+    pub fills: Option<Vec<SorFillInfo>>,
+}
+
+/// Fill of a SOR order. Allocations have no trade id (-1), `alloc_id` identifies them instead.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SorFillInfo {
+    pub match_type: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub commission: Number,
+    pub commission_asset: String,
+    pub trade_id: i64,
+    pub alloc_id: Option<u64>,
+}
+
+/// OCO, OTO or OTOCO order list.
+///
+/// `order_reports` is only filled in the responses to placing or canceling the list.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: u64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+    #[serde(default)]
+    pub order_reports: Vec<OrderReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+/// State of one order of an order list.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderReport {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    /// Set when the order was canceled
+    pub orig_client_order_id: Option<String>,
+    pub transact_time: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Number,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub status: String,
     pub time_in_force: String,
     #[serde(rename = "type")]
     pub type_name: String,
     pub side: String,
-    pub fills: Option<Vec<FillInfo>>,
-}
-
-fn default_stop_price() -> f64 {
-    0.0
+    pub self_trade_prevention_mode: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FillInfo {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
     pub trade_id: Option<u64>,
 }
@@ -266,13 +609,13 @@ pub struct OrderBook {
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct Bids {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 impl Bids {
-    pub fn new(price: f64, qty: f64) -> Bids {
+    pub fn new(price: Number, qty: Number) -> Bids {
         Bids { price, qty }
     }
 }
@@ -280,9 +623,9 @@ impl Bids {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Asks {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -305,14 +648,14 @@ pub enum Prices {
 pub struct SymbolPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -332,13 +675,56 @@ pub enum KlineSummaries {
 pub struct Tickers {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub bid_qty: f64,
+    pub bid_qty: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_qty: f64,
+    pub ask_qty: Number,
+}
+
+// Ticker statistics over a rolling window, a trading day or 24 hours. The fields marked
+// optional are only sent with the FULL ticker type, or only by the 24hr endpoint.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerStats {
+    pub symbol: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price_change: Option<Number>,
+    #[serde(default)]
+    pub price_change_percent: Option<String>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub weighted_avg_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub prev_close_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub last_qty: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub bid_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub bid_qty: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub ask_price: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub ask_qty: Option<Number>,
+    #[serde(with = "string_or_float")]
+    pub open_price: Number,
+    #[serde(with = "string_or_float")]
+    pub high_price: Number,
+    #[serde(with = "string_or_float")]
+    pub low_price: Number,
+    #[serde(with = "string_or_float")]
+    pub last_price: Number,
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_volume: Number,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -346,10 +732,10 @@ pub struct Tickers {
 pub struct TradeHistory {
     pub id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
-    pub commission: String,
+    pub qty: Number,
+    pub commission: NumberString,
     pub commission_asset: String,
     pub time: u64,
     pub is_buyer: bool,
@@ -357,29 +743,44 @@ pub struct TradeHistory {
     pub is_best_match: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Number,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
     pub symbol: String,
-    pub price_change: String,
+    pub price_change: NumberString,
     pub price_change_percent: String,
-    pub weighted_avg_price: String,
+    pub weighted_avg_price: NumberString,
     #[serde(with = "string_or_float")]
-    pub prev_close_price: f64,
+    pub prev_close_price: Number,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: i64,
@@ -402,9 +803,9 @@ pub struct AggTrade {
     #[serde(rename = "M")]
     pub best_match: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -449,11 +850,11 @@ pub struct EventBalance {
     #[serde(rename = "a")]
     pub asset: String,
     #[serde(rename = "wb")]
-    pub wallet_balance: String,
+    pub wallet_balance: NumberString,
     #[serde(rename = "cw")]
-    pub cross_wallet_balance: String,
+    pub cross_wallet_balance: NumberString,
     #[serde(rename = "bc")]
-    pub balance_change: String, // Balance Change except PnL and Commission
+    pub balance_change: NumberString, // Balance Change except PnL and Commission
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -462,17 +863,17 @@ pub struct EventPosition {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "pa")]
-    pub position_amount: String,
+    pub position_amount: NumberString,
     #[serde(rename = "ep")]
-    pub entry_price: String,
+    pub entry_price: NumberString,
     #[serde(rename = "cr")]
-    pub accumulated_realized: String, // (Pre-fee) Accumulated Realized
+    pub accumulated_realized: NumberString, // (Pre-fee) Accumulated Realized
     #[serde(rename = "up")]
-    pub unrealized_pnl: String,
+    pub unrealized_pnl: NumberString,
     #[serde(rename = "mt")]
     pub margin_type: String,
     #[serde(rename = "iw")]
-    pub isolated_wallet: String,
+    pub isolated_wallet: NumberString,
     #[serde(rename = "ps")]
     pub position_side: String,
 }
@@ -518,10 +919,10 @@ pub struct OrderTradeEvent {
     pub time_in_force: String,
 
     #[serde(rename = "q")]
-    pub qty: String,
+    pub qty: NumberString,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(skip, rename = "P")]
     pub p_ignore: String,
@@ -548,16 +949,16 @@ pub struct OrderTradeEvent {
     pub order_id: u64,
 
     #[serde(rename = "l")]
-    pub qty_last_filled_trade: String,
+    pub qty_last_filled_trade: NumberString,
 
     #[serde(rename = "z")]
-    pub accumulated_qty_filled_trades: String,
+    pub accumulated_qty_filled_trades: NumberString,
 
     #[serde(rename = "L")]
-    pub price_last_filled_trade: String,
+    pub price_last_filled_trade: NumberString,
 
     #[serde(rename = "n")]
-    pub commission: String,
+    pub commission: NumberString,
 
     #[serde(skip, rename = "N")]
     pub asset_commisioned: Option<String>,
@@ -604,10 +1005,10 @@ pub struct AggrTradesEvent {
     pub aggregated_trade_id: u64,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(rename = "q")]
-    pub qty: String,
+    pub qty: NumberString,
 
     #[serde(rename = "f")]
     pub first_break_trade_id: u64,
@@ -648,10 +1049,10 @@ pub struct TradeEvent {
     pub trade_id: u64,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(rename = "q")]
-    pub qty: String,
+    pub qty: NumberString,
 
     #[serde(rename = "b")]
     pub buyer_order_id: u64,
@@ -682,7 +1083,7 @@ pub struct IndexPriceEvent {
     pub pair: String,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 }
 // https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream
 // https://binance-docs.github.io/apidocs/delivery/en/#mark-price-stream
@@ -693,7 +1094,7 @@ pub struct MarkPriceEvent {
     pub event_time: u64,
 
     #[serde(rename = "P")]
-    pub estimate_settle_price: String,
+    pub estimate_settle_price: NumberString,
 
     #[serde(rename = "T")]
     pub next_funding_time: u64,
@@ -702,10 +1103,10 @@ pub struct MarkPriceEvent {
     pub event_type: String,
 
     #[serde(rename = "i")]
-    pub index_price: Option<String>,
+    pub index_price: Option<NumberString>,
 
     #[serde(rename = "p")]
-    pub mark_price: String,
+    pub mark_price: NumberString,
 
     #[serde(rename = "r")]
     pub funding_rate: String,
@@ -746,22 +1147,22 @@ pub struct LiquidationOrder {
     pub time_in_force: String,
 
     #[serde(rename = "q")]
-    pub original_quantity: String,
+    pub original_quantity: NumberString,
 
     #[serde(rename = "p")]
-    pub price: String,
+    pub price: NumberString,
 
     #[serde(rename = "ap")]
-    pub average_price: String,
+    pub average_price: NumberString,
 
     #[serde(rename = "X")]
     pub order_status: String,
 
     #[serde(rename = "l")]
-    pub order_last_filled_quantity: String,
+    pub order_last_filled_quantity: NumberString,
 
     #[serde(rename = "z")]
-    pub order_filled_accumulated_quantity: String,
+    pub order_filled_accumulated_quantity: NumberString,
 
     #[serde(rename = "T")]
     pub order_trade_time: u64,
@@ -777,16 +1178,16 @@ pub struct BookTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "b")]
-    pub best_bid: String,
+    pub best_bid: NumberString,
 
     #[serde(rename = "B")]
-    pub best_bid_qty: String,
+    pub best_bid_qty: NumberString,
 
     #[serde(rename = "a")]
-    pub best_ask: String,
+    pub best_ask: NumberString,
 
     #[serde(rename = "A")]
-    pub best_ask_qty: String,
+    pub best_ask_qty: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -802,49 +1203,49 @@ pub struct DayTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "p")]
-    pub price_change: String,
+    pub price_change: NumberString,
 
     #[serde(rename = "P")]
     pub price_change_percent: String,
 
     #[serde(rename = "w")]
-    pub average_price: String,
+    pub average_price: NumberString,
 
     #[serde(rename = "x")]
-    pub prev_close: String,
+    pub prev_close: NumberString,
 
     #[serde(rename = "c")]
-    pub current_close: String,
+    pub current_close: NumberString,
 
     #[serde(rename = "Q")]
-    pub current_close_qty: String,
+    pub current_close_qty: NumberString,
 
     #[serde(rename = "b")]
-    pub best_bid: String,
+    pub best_bid: NumberString,
 
     #[serde(rename = "B")]
-    pub best_bid_qty: String,
+    pub best_bid_qty: NumberString,
 
     #[serde(rename = "a")]
-    pub best_ask: String,
+    pub best_ask: NumberString,
 
     #[serde(rename = "A")]
-    pub best_ask_qty: String,
+    pub best_ask_qty: NumberString,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "q")]
-    pub quote_volume: String,
+    pub quote_volume: NumberString,
 
     #[serde(rename = "O")]
     pub open_time: u64,
@@ -875,31 +1276,31 @@ pub struct WindowTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "p")]
-    pub price_change: String,
+    pub price_change: NumberString,
 
     #[serde(rename = "P")]
     pub price_change_percent: String,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "c")]
-    pub current_close: String,
+    pub current_close: NumberString,
 
     #[serde(rename = "w")]
-    pub average_price: String,
+    pub average_price: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "q")]
-    pub quote_volume: String,
+    pub quote_volume: NumberString,
 
     #[serde(rename = "O")]
     pub open_time: u64,
@@ -930,22 +1331,22 @@ pub struct MiniTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: NumberString,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "q")]
-    pub quote_volume: String,
+    pub quote_volume: NumberString,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1007,25 +1408,25 @@ pub struct IndexKlineEvent {
 pub struct KlineSummary {
     pub open_time: i64,
 
-    pub open: String,
+    pub open: NumberString,
 
-    pub high: String,
+    pub high: NumberString,
 
-    pub low: String,
+    pub low: NumberString,
 
-    pub close: String,
+    pub close: NumberString,
 
-    pub volume: String,
+    pub volume: NumberString,
 
     pub close_time: i64,
 
-    pub quote_asset_volume: String,
+    pub quote_asset_volume: NumberString,
 
     pub number_of_trades: i64,
 
-    pub taker_buy_base_asset_volume: String,
+    pub taker_buy_base_asset_volume: NumberString,
 
-    pub taker_buy_quote_asset_volume: String,
+    pub taker_buy_quote_asset_volume: NumberString,
 }
 
 fn get_value(row: &[Value], index: usize, name: &'static str) -> Result<Value> {
@@ -1085,19 +1486,19 @@ pub struct Kline {
     pub last_trade_id: i64,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "n")]
     pub number_of_trades: i64,
@@ -1106,13 +1507,13 @@ pub struct Kline {
     pub is_final_bar: bool,
 
     #[serde(rename = "q")]
-    pub quote_asset_volume: String,
+    pub quote_asset_volume: NumberString,
 
     #[serde(rename = "V")]
-    pub taker_buy_base_asset_volume: String,
+    pub taker_buy_base_asset_volume: NumberString,
 
     #[serde(rename = "Q")]
-    pub taker_buy_quote_asset_volume: String,
+    pub taker_buy_quote_asset_volume: NumberString,
 
     #[serde(skip, rename = "B")]
     pub ignore_me: String,
@@ -1137,19 +1538,19 @@ pub struct ContinuousKline {
     pub last_trade_id: i64,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "n")]
     pub number_of_trades: i64,
@@ -1158,13 +1559,13 @@ pub struct ContinuousKline {
     pub is_final_bar: bool,
 
     #[serde(rename = "q")]
-    pub quote_volume: String,
+    pub quote_volume: NumberString,
 
     #[serde(rename = "V")]
-    pub active_buy_volume: String,
+    pub active_buy_volume: NumberString,
 
     #[serde(rename = "Q")]
-    pub active_volume_buy_quote: String,
+    pub active_volume_buy_quote: NumberString,
 
     #[serde(skip, rename = "B")]
     pub ignore_me: String,
@@ -1193,19 +1594,19 @@ pub struct IndexKline {
     pub last_trade_id: i64,
 
     #[serde(rename = "o")]
-    pub open: String,
+    pub open: NumberString,
 
     #[serde(rename = "c")]
-    pub close: String,
+    pub close: NumberString,
 
     #[serde(rename = "h")]
-    pub high: String,
+    pub high: NumberString,
 
     #[serde(rename = "l")]
-    pub low: String,
+    pub low: NumberString,
 
     #[serde(rename = "v")]
-    pub volume: String,
+    pub volume: NumberString,
 
     #[serde(rename = "n")]
     pub number_of_trades: i64,
@@ -1262,24 +1663,24 @@ pub struct CoinInfo {
     pub coin: String,
     pub deposit_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Number,
     #[serde(with = "string_or_float")]
-    pub ipoable: f64,
+    pub ipoable: Number,
     #[serde(with = "string_or_float")]
-    pub ipoing: f64,
+    pub ipoing: Number,
     pub is_legal_money: bool,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
     pub name: String,
     pub network_list: Vec<Network>,
     #[serde(with = "string_or_float")]
-    pub storage: f64,
+    pub storage: Number,
     pub trading: bool,
     pub withdraw_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Number,
 }
 
 /// Part of the Savings API get all coins response
@@ -1305,9 +1706,9 @@ pub struct Network {
     pub withdraw_desc: Option<String>,
     pub withdraw_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     #[serde(with = "string_or_float")]
-    pub withdraw_min: f64,
+    pub withdraw_min: Number,
     // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
     // pub update_time: Option<u64>,
     pub withdraw_integer_multiple: Option<String>,
@@ -1317,11 +1718,11 @@ pub struct Network {
 #[serde(rename_all = "camelCase")]
 pub struct AssetDetail {
     #[serde(with = "string_or_float")]
-    pub min_withdraw_amount: f64,
+    pub min_withdraw_amount: Number,
     /// false if ALL of networks' are false
     pub deposit_status: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     /// false if ALL of networks' are false
     pub withdraw_status: bool,
     /// reason
@@ -1336,11 +1737,55 @@ pub struct DepositAddress {
     pub url: String,
 }
 
+/// A number sent by the API as a string, or as a JSON number.
+pub(crate) trait ApiNumber: Sized {
+    fn from_api_str(value: &str) -> std::result::Result<Self, String>;
+    fn from_api_number(value: &serde_json::Number) -> std::result::Result<Self, String>;
+}
+
+impl ApiNumber for f64 {
+    fn from_api_str(value: &str) -> std::result::Result<Self, String> {
+        if value == "INF" {
+            Ok(f64::INFINITY)
+        } else {
+            value.parse().map_err(|e| format!("{}", e))
+        }
+    }
+
+    fn from_api_number(value: &serde_json::Number) -> std::result::Result<Self, String> {
+        value
+            .as_f64()
+            .ok_or_else(|| format!("{} is not a valid f64", value))
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl ApiNumber for rust_decimal::Decimal {
+    fn from_api_str(value: &str) -> std::result::Result<Self, String> {
+        use std::str::FromStr;
+
+        if value == "INF" {
+            Ok(rust_decimal::Decimal::MAX)
+        } else {
+            rust_decimal::Decimal::from_str(value)
+                .or_else(|_| rust_decimal::Decimal::from_scientific(value))
+                .map_err(|e| format!("{}", e))
+        }
+    }
+
+    // with `arbitrary_precision` the number keeps the digits of the JSON token
+    fn from_api_number(value: &serde_json::Number) -> std::result::Result<Self, String> {
+        Self::from_api_str(&value.to_string())
+    }
+}
+
 pub(crate) mod string_or_float {
     use std::fmt;
 
     use serde::{de, Serializer, Deserialize, Deserializer};
 
+    use super::ApiNumber;
+
     pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
     where
         T: fmt::Display,
@@ -1349,26 +1794,21 @@ pub(crate) mod string_or_float {
         serializer.collect_str(value)
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
     where
         D: Deserializer<'de>,
+        T: ApiNumber,
     {
         #[derive(Deserialize)]
         #[serde(untagged)]
         enum StringOrFloat {
             String(String),
-            Float(f64),
+            Float(serde_json::Number),
         }
 
         match StringOrFloat::deserialize(deserializer)? {
-            StringOrFloat::String(s) => {
-                if s == "INF" {
-                    Ok(f64::INFINITY)
-                } else {
-                    s.parse().map_err(de::Error::custom)
-                }
-            }
-            StringOrFloat::Float(i) => Ok(i),
+            StringOrFloat::String(s) => T::from_api_str(&s).map_err(de::Error::custom),
+            StringOrFloat::Float(i) => T::from_api_number(&i).map_err(de::Error::custom),
         }
     }
 }
@@ -1376,7 +1816,9 @@ pub(crate) mod string_or_float {
 pub(crate) mod string_or_float_opt {
     use std::fmt;
 
-    use serde::{Serializer, Deserialize, Deserializer};
+    use serde::{de, Serializer, Deserialize, Deserializer};
+
+    use super::ApiNumber;
 
     pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1389,20 +1831,29 @@ pub(crate) mod string_or_float_opt {
         }
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    // null and "" (e.g. the mark price of old funding rates) are read as None
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
     where
         D: Deserializer<'de>,
+        T: ApiNumber,
     {
         #[derive(Deserialize)]
         #[serde(untagged)]
         enum StringOrFloat {
             String(String),
-            Float(f64),
+            Float(serde_json::Number),
         }
 
-        Ok(Some(crate::model::string_or_float::deserialize(
-            deserializer,
-        )?))
+        match Option::<StringOrFloat>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(StringOrFloat::String(s)) if s.is_empty() => Ok(None),
+            Some(StringOrFloat::String(s)) => {
+                T::from_api_str(&s).map(Some).map_err(de::Error::custom)
+            }
+            Some(StringOrFloat::Float(i)) => {
+                T::from_api_number(&i).map(Some).map_err(de::Error::custom)
+            }
+        }
     }
 }
 
@@ -1498,6 +1949,7 @@ fn test_account_update_event() {
 
     let res = r#"AccountUpdateEvent { event_type: "ACCOUNT_UPDATE", event_time: 1564745798939, data: AccountUpdateDataEvent { reason: "ORDER", balances: [EventBalance { asset: "USDT", wallet_balance: "122624.12345678", cross_wallet_balance: "100.12345678", balance_change: "50.12345678" }, EventBalance { asset: "BUSD", wallet_balance: "1.00000000", cross_wallet_balance: "0.00000000", balance_change: "-49.12345678" }], positions: [EventPosition { symbol: "BTCUSDT", position_amount: "0", entry_price: "0.00000", accumulated_realized: "200", unrealized_pnl: "0", margin_type: "isolated", isolated_wallet: "0.00000000", position_side: "BOTH" }, EventPosition { symbol: "BTCUSDT", position_amount: "20", entry_price: "6563.66500", accumulated_realized: "0", unrealized_pnl: "2850.21200", margin_type: "isolated", isolated_wallet: "13200.70726908", position_side: "LONG" }, EventPosition { symbol: "BTCUSDT", position_amount: "-10", entry_price: "6563.86000", accumulated_realized: "-45.04000000", unrealized_pnl: "-1423.15600", margin_type: "isolated", isolated_wallet: "6570.42511771", position_side: "SHORT" }] } }"#;
     let v: AccountUpdateEvent = serde_json::from_str(json).unwrap();
-    assert_eq!(format!("{:?}", v), res);
+    // NumberString values are quoted as String and bare as Decimal
+    assert_eq!(format!("{:?}", v).replace('"', ""), res.replace('"', ""));
     //let event =  from_value::<AccountUpdateEvent>(json).unwrap();
 }
@@ -0,0 +1,276 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::header::HeaderMap;
+
+use crate::errors::{ErrorKind, Result};
+use crate::model::RateLimit;
+
+const USED_WEIGHT_HEADER: &str = "x-mbx-used-weight-";
+const ORDER_COUNT_HEADER: &str = "x-mbx-order-count-";
+
+/// What the client does when the usage reported by Binance has reached a known limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitPolicy {
+    /// Only record the usage, requests are always sent.
+    #[default]
+    Track,
+    /// Wait until the interval rolls over before sending the request.
+    Throttle,
+    /// Return `ErrorKind::RateLimitExceeded` without sending the request.
+    FailFast,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RateLimitKind {
+    /// `X-MBX-USED-WEIGHT-*`, counted for every request.
+    RequestWeight,
+    /// `X-MBX-ORDER-COUNT-*`, counted for new orders only.
+    Orders,
+}
+
+impl fmt::Display for RateLimitKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::RequestWeight => write!(f, "REQUEST_WEIGHT"),
+            Self::Orders => write!(f, "ORDERS"),
+        }
+    }
+}
+
+/// Usage of one limit in the current interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitUsage {
+    /// Base path of the API the usage counts against, e.g. `/api`, `/fapi` or `/dapi`.
+    pub api: String,
+    pub kind: RateLimitKind,
+    pub interval: Duration,
+    pub used: u64,
+    /// Known once `exchange_info()` has been called on a client sharing this limiter.
+    pub limit: Option<u64>,
+}
+
+impl fmt::Display for RateLimitUsage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.limit {
+            Some(limit) => write!(
+                f,
+                "{} {}/{} per {:?}",
+                self.kind, self.used, limit, self.interval
+            ),
+            None => write!(f, "{} {} per {:?}", self.kind, self.used, self.interval),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Counter {
+    used: u64,
+    window: u64,
+    limit: Option<u64>,
+}
+
+/// Tracks the request weight and order counts Binance reports in its response headers.
+///
+/// Intervals are fixed windows (a minute starts at :00), so the usage of an interval
+/// goes back to zero once it rolls over. Counters are kept per host and API base path
+/// (`/api`, `/fapi`, `/dapi`, ...), which lets spot and futures clients built from the
+/// same `Config` share one limiter, even when two APIs share a host as on the testnet.
+///
+/// Since the weight of a request is only known once it has been answered, a request is
+/// held back (or rejected) once the reported usage has reached the limit.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    policy: RateLimitPolicy,
+    counters: Mutex<BTreeMap<CounterKey, Counter>>,
+}
+
+// host, API base path, kind and interval
+type CounterKey = (String, String, RateLimitKind, Duration);
+
+impl RateLimiter {
+    pub fn new(policy: RateLimitPolicy) -> Self {
+        RateLimiter {
+            policy,
+            counters: Mutex::default(),
+        }
+    }
+
+    pub fn policy(&self) -> RateLimitPolicy {
+        self.policy
+    }
+
+    /// Set the limits of the `api` base path of `host` from `ExchangeInformation::rate_limits`.
+    pub fn set_limits(&self, host: &str, api: &str, limits: &[RateLimit]) {
+        let mut counters = self.counters();
+        for limit in limits {
+            let kind = match limit.rate_limit_type.as_str() {
+                "REQUEST_WEIGHT" => RateLimitKind::RequestWeight,
+                "ORDERS" => RateLimitKind::Orders,
+                _ => continue,
+            };
+            let Some(interval) = interval_from_name(&limit.interval, limit.interval_num) else {
+                continue;
+            };
+            counters
+                .entry((host.into(), api.into(), kind, interval))
+                .or_default()
+                .limit = Some(limit.limit);
+        }
+    }
+
+    /// Record the usage reported in the headers of a response from the `api` base path
+    /// of `host`.
+    pub fn update_from_headers(&self, host: &str, api: &str, headers: &HeaderMap) {
+        let now = now_millis();
+        let mut counters = self.counters();
+        for (name, value) in headers {
+            let name = name.as_str();
+            let (kind, suffix) = if let Some(suffix) = name.strip_prefix(USED_WEIGHT_HEADER) {
+                (RateLimitKind::RequestWeight, suffix)
+            } else if let Some(suffix) = name.strip_prefix(ORDER_COUNT_HEADER) {
+                (RateLimitKind::Orders, suffix)
+            } else {
+                continue;
+            };
+            let Some(interval) = interval_from_suffix(suffix) else {
+                continue;
+            };
+            let Some(used) = value.to_str().ok().and_then(|v| v.parse::<u64>().ok()) else {
+                continue;
+            };
+
+            let window = now / interval_millis(interval);
+            let counter = counters
+                .entry((host.into(), api.into(), kind, interval))
+                .or_default();
+            // responses of concurrent requests can come back out of order
+            if counter.window == window {
+                counter.used = counter.used.max(used);
+            } else {
+                counter.used = used;
+                counter.window = window;
+            }
+        }
+    }
+
+    /// Usage of every limit seen for `host`, all APIs included, in its current interval.
+    pub fn usage(&self, host: &str) -> Vec<RateLimitUsage> {
+        let now = now_millis();
+        self.counters()
+            .iter()
+            .filter(|((h, _, _, _), _)| h == host)
+            .map(|((_, api, kind, interval), counter)| RateLimitUsage {
+                api: api.clone(),
+                kind: *kind,
+                interval: *interval,
+                used: current_usage(counter, *interval, now),
+                limit: counter.limit,
+            })
+            .collect()
+    }
+
+    /// Returns how long to wait before a request to the `api` base path of `host` can be
+    /// sent, or an error with `RateLimitPolicy::FailFast`. Order counts only apply when
+    /// `places_order`.
+    pub(crate) fn check(
+        &self, host: &str, api: &str, places_order: bool,
+    ) -> Result<Option<Duration>> {
+        if self.policy == RateLimitPolicy::Track {
+            return Ok(None);
+        }
+
+        let now = now_millis();
+        let mut wait: Option<Duration> = None;
+        for ((h, a, kind, interval), counter) in self.counters().iter() {
+            if h != host || a != api || (*kind == RateLimitKind::Orders && !places_order) {
+                continue;
+            }
+            let Some(limit) = counter.limit else {
+                continue;
+            };
+            let used = current_usage(counter, *interval, now);
+            if used < limit {
+                continue;
+            }
+
+            let millis = interval_millis(*interval);
+            let retry_after = Duration::from_millis((counter.window + 1) * millis - now);
+            if self.policy == RateLimitPolicy::FailFast {
+                let usage = RateLimitUsage {
+                    api: api.into(),
+                    kind: *kind,
+                    interval: *interval,
+                    used,
+                    limit: Some(limit),
+                };
+                return Err(ErrorKind::RateLimitExceeded(usage, retry_after).into());
+            }
+            wait = wait.max(Some(retry_after));
+        }
+
+        Ok(wait)
+    }
+
+    fn counters(&self) -> MutexGuard<'_, BTreeMap<CounterKey, Counter>> {
+        self.counters.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Base path of the API a request path belongs to, e.g. `/fapi` for `/fapi/v1/order`.
+pub(crate) fn api_base_path(path: &str) -> &str {
+    match path.get(1..).and_then(|rest| rest.find('/')) {
+        Some(end) => &path[..end + 1],
+        None => path,
+    }
+}
+
+fn current_usage(counter: &Counter, interval: Duration, now: u64) -> u64 {
+    if counter.window == now / interval_millis(interval) {
+        counter.used
+    } else {
+        0
+    }
+}
+
+// e.g. `1m` from `x-mbx-used-weight-1m`
+fn interval_from_suffix(suffix: &str) -> Option<Duration> {
+    let unit = suffix.chars().last()?;
+    let num: u64 = suffix[..suffix.len() - unit.len_utf8()].parse().ok()?;
+    unit_duration(unit.to_ascii_lowercase()).map(|d| d * num as u32)
+}
+
+// e.g. `MINUTE` with `intervalNum` 1
+fn interval_from_name(name: &str, num: u16) -> Option<Duration> {
+    let unit = match name {
+        "SECOND" => 's',
+        "MINUTE" => 'm',
+        "HOUR" => 'h',
+        "DAY" => 'd',
+        _ => return None,
+    };
+    unit_duration(unit).map(|d| d * u32::from(num))
+}
+
+fn unit_duration(unit: char) -> Option<Duration> {
+    match unit {
+        's' => Some(Duration::from_secs(1)),
+        'm' => Some(Duration::from_secs(60)),
+        'h' => Some(Duration::from_secs(60 * 60)),
+        'd' => Some(Duration::from_secs(24 * 60 * 60)),
+        _ => None,
+    }
+}
+
+fn interval_millis(interval: Duration) -> u64 {
+    (interval.as_millis() as u64).max(1)
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::errors::Error;
+
+/// How non-idempotent requests (every signed POST: new orders, transfers, ...) are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonIdempotentRetry {
+    /// Never retry them.
+    Never,
+    /// Retry only when Binance did not process the request: the connection could not be
+    /// established, or the request was rejected with 429 or 418.
+    #[default]
+    Rejected,
+}
+
+/// Retry policy for transient REST failures.
+///
+/// A request is retried on connection errors, timeouts, 5xx, 429 and 418, waiting
+/// `initial_backoff * 2^n` (up to `max_backoff`, with jitter) between attempts. On 429
+/// and 418 the `Retry-After` header sent by Binance takes precedence; if it asks for
+/// longer than `max_backoff`, the error is returned instead.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Attempts per request, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub jitter: bool,
+    pub non_idempotent: NonIdempotentRetry,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            non_idempotent: NonIdempotentRetry::default(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32) -> Self {
+        Self::default().set_max_attempts(max_attempts)
+    }
+
+    pub fn set_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn set_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn set_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn set_non_idempotent(mut self, non_idempotent: NonIdempotentRetry) -> Self {
+        self.non_idempotent = non_idempotent;
+        self
+    }
+
+    /// Delay before retrying a request that failed without a response, if it should be.
+    pub(crate) fn retry_error(
+        &self, attempt: u32, error: &Error, idempotent: bool,
+    ) -> Option<Duration> {
+        let retryable = if idempotent {
+            error.is_connect() || error.is_timeout()
+        } else {
+            // a timed out order may still have reached the matching engine
+            self.non_idempotent == NonIdempotentRetry::Rejected && error.is_connect()
+        };
+        self.delay(attempt, retryable, None)
+    }
+
+    /// Delay before retrying a request answered with `status`, if it should be.
+    pub(crate) fn retry_status(
+        &self, attempt: u32, status: StatusCode, retry_after: Option<Duration>, idempotent: bool,
+    ) -> Option<Duration> {
+        let rejected = status == StatusCode::TOO_MANY_REQUESTS || status.as_u16() == 418;
+        let retryable = if idempotent {
+            rejected
+                || matches!(
+                    status,
+                    StatusCode::INTERNAL_SERVER_ERROR
+                        | StatusCode::BAD_GATEWAY
+                        | StatusCode::SERVICE_UNAVAILABLE
+                        | StatusCode::GATEWAY_TIMEOUT
+                )
+        } else {
+            // on 5xx the execution status is unknown, only 429/418 are known to be rejected
+            self.non_idempotent == NonIdempotentRetry::Rejected && rejected
+        };
+        self.delay(attempt, retryable, retry_after.filter(|_| rejected))
+    }
+
+    fn delay(
+        &self, attempt: u32, retryable: bool, retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if !retryable || attempt >= self.max_attempts {
+            return None;
+        }
+        if let Some(retry_after) = retry_after {
+            if retry_after > self.max_backoff {
+                return None;
+            }
+            return Some(retry_after);
+        }
+
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(self.max_backoff);
+        if self.jitter {
+            // "equal jitter": somewhere between half and the full backoff
+            let half = backoff / 2;
+            Some(half + half.mul_f64(fastrand::f64()))
+        } else {
+            Some(backoff)
+        }
+    }
+}
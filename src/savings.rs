@@ -1,41 +1,65 @@
 use crate::util::build_signed_request;
 use crate::model::{AssetDetail, CoinInfo, DepositAddress, SpotFuturesTransferType, TransactionId};
 use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
 use crate::errors::Result;
 use std::collections::BTreeMap;
 use crate::api::API;
 use crate::api::Sapi;
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncSavings")
+)]
 #[derive(Clone)]
 pub struct Savings {
     pub client: Client,
     pub recv_window: u64,
 }
 
+// The async twin of `Savings` is generated by renaming every `Savings` ident,
+// which would also rename `API::Savings`, so the endpoint is built out here.
+fn sapi(route: Sapi) -> API {
+    API::Savings(route)
+}
+
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncSavings")
+)]
 impl Savings {
     /// Get all coins available for deposit and withdrawal
-    pub fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
+    pub async fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
         let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
         self.client
-            .get_signed(API::Savings(Sapi::AllCoins), Some(request))
+            .get_signed(sapi(Sapi::AllCoins), Some(request))
+            .await
     }
 
     /// Fetch details of assets supported on Binance.
-    pub fn asset_detail(&self, asset: Option<String>) -> Result<BTreeMap<String, AssetDetail>> {
+    pub async fn asset_detail(
+        &self, asset: Option<String>,
+    ) -> Result<BTreeMap<String, AssetDetail>> {
         let mut parameters = BTreeMap::new();
         if let Some(asset) = asset {
             parameters.insert("asset".into(), asset);
         }
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
-            .get_signed(API::Savings(Sapi::AssetDetail), Some(request))
+            .get_signed(sapi(Sapi::AssetDetail), Some(request))
+            .await
     }
 
     /// Fetch deposit address with network.
     ///
     /// You can get the available networks using `get_all_coins`.
     /// If no network is specified, the address for the default network is returned.
-    pub fn deposit_address<S>(&self, coin: S, network: Option<String>) -> Result<DepositAddress>
+    pub async fn deposit_address<S>(
+        &self, coin: S, network: Option<String>,
+    ) -> Result<DepositAddress>
     where
         S: Into<String>,
     {
@@ -46,10 +70,11 @@ impl Savings {
         }
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
-            .get_signed(API::Savings(Sapi::DepositAddress), Some(request))
+            .get_signed(sapi(Sapi::DepositAddress), Some(request))
+            .await
     }
 
-    pub fn transfer_funds<S>(
+    pub async fn transfer_funds<S>(
         &self, asset: S, amount: f64, transfer_type: SpotFuturesTransferType,
     ) -> Result<TransactionId>
     where
@@ -61,6 +86,7 @@ impl Savings {
         parameters.insert("type".into(), (transfer_type as u8).to_string());
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
-            .post_signed(API::Savings(Sapi::SpotFuturesTransfer), request)
+            .post_signed(sapi(Sapi::SpotFuturesTransfer), request)
+            .await
     }
 }
@@ -0,0 +1,133 @@
+use std::fmt;
+
+use hex::encode as hex_encode;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+#[cfg(any(feature = "rsa", feature = "ed25519"))]
+use {
+    crate::errors::Result, base64::Engine, base64::engine::general_purpose::STANDARD as BASE64,
+    error_chain::bail, std::path::Path,
+};
+
+/// Signs the query string of `SIGNED` (TRADE and USER_DATA) requests.
+///
+/// `HmacSigner` is used with the secret key given to `Binance::new`; set another signer
+/// with `Config::set_signer` to use an RSA or Ed25519 API key (`rsa` and `ed25519`
+/// features).
+pub trait Signer: fmt::Debug + Send + Sync {
+    /// Signature of `payload`, as it goes into the `signature` parameter.
+    fn sign(&self, payload: &str) -> String;
+}
+
+/// HMAC-SHA256 with the secret key, hex encoded.
+pub struct HmacSigner {
+    secret_key: String,
+}
+
+impl HmacSigner {
+    pub fn new<S: Into<String>>(secret_key: S) -> Self {
+        HmacSigner {
+            secret_key: secret_key.into(),
+        }
+    }
+}
+
+impl Signer for HmacSigner {
+    fn sign(&self, payload: &str) -> String {
+        let mut signed_key = Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
+        signed_key.update(payload.as_bytes());
+        hex_encode(signed_key.finalize().into_bytes())
+    }
+}
+
+impl fmt::Debug for HmacSigner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HmacSigner").finish_non_exhaustive()
+    }
+}
+
+/// RSASSA-PKCS1-v1_5 with SHA-256, base64 encoded.
+#[cfg(feature = "rsa")]
+pub struct RsaSigner {
+    key: rsa::pkcs1v15::SigningKey<Sha256>,
+}
+
+#[cfg(feature = "rsa")]
+impl RsaSigner {
+    /// Load a PKCS#8 (`BEGIN PRIVATE KEY`) or PKCS#1 (`BEGIN RSA PRIVATE KEY`) PEM key.
+    pub fn from_pem(pem: &str) -> Result<Self> {
+        use rsa::RsaPrivateKey;
+        use rsa::pkcs1::DecodeRsaPrivateKey;
+        use rsa::pkcs8::DecodePrivateKey;
+
+        let key = match RsaPrivateKey::from_pkcs8_pem(pem) {
+            Ok(key) => key,
+            Err(pkcs8_error) => match RsaPrivateKey::from_pkcs1_pem(pem) {
+                Ok(key) => key,
+                Err(_) => bail!(format!("Invalid RSA private key: {}", pkcs8_error)),
+            },
+        };
+        Ok(RsaSigner {
+            key: rsa::pkcs1v15::SigningKey::new(key),
+        })
+    }
+
+    pub fn from_pem_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_pem(&std::fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(feature = "rsa")]
+impl Signer for RsaSigner {
+    fn sign(&self, payload: &str) -> String {
+        use rsa::signature::{SignatureEncoding, Signer as _};
+        BASE64.encode(self.key.sign(payload.as_bytes()).to_bytes())
+    }
+}
+
+#[cfg(feature = "rsa")]
+impl fmt::Debug for RsaSigner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RsaSigner").finish_non_exhaustive()
+    }
+}
+
+/// Ed25519, base64 encoded.
+#[cfg(feature = "ed25519")]
+pub struct Ed25519Signer {
+    key: ed25519_dalek::SigningKey,
+}
+
+#[cfg(feature = "ed25519")]
+impl Ed25519Signer {
+    /// Load a PKCS#8 (`BEGIN PRIVATE KEY`) PEM key, as generated by Binance's key tool
+    /// or `openssl genpkey -algorithm ed25519`.
+    pub fn from_pem(pem: &str) -> Result<Self> {
+        use ed25519_dalek::pkcs8::DecodePrivateKey;
+
+        match ed25519_dalek::SigningKey::from_pkcs8_pem(pem) {
+            Ok(key) => Ok(Ed25519Signer { key }),
+            Err(e) => bail!(format!("Invalid Ed25519 private key: {}", e)),
+        }
+    }
+
+    pub fn from_pem_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_pem(&std::fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl Signer for Ed25519Signer {
+    fn sign(&self, payload: &str) -> String {
+        use ed25519_dalek::Signer as _;
+        BASE64.encode(self.key.sign(payload.as_bytes()).to_bytes())
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl fmt::Debug for Ed25519Signer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Ed25519Signer").finish_non_exhaustive()
+    }
+}
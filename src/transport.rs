@@ -0,0 +1,212 @@
+use std::fmt;
+use std::sync::Arc;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+
+use error_chain::bail;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+
+use crate::config::ConnectionConfig;
+use crate::errors::Result;
+
+/// A request as built by the client: signed, with its headers, ready to be sent.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<String>,
+}
+
+/// A response with its body fully read.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// Sends the requests of `Client`.
+///
+/// `ReqwestTransport` is used by default; set another one with `Config::set_transport`
+/// to serve requests from memory in tests, wrap them with middleware or route them
+/// through a different HTTP stack. Connection failures should be returned as
+/// `ErrorKind::ReqError` or `ErrorKind::IoError` for the retry policy to see them.
+pub trait Transport: fmt::Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        (**self).send(request)
+    }
+}
+
+#[cfg(feature = "async")]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<HttpResponse>> + Send + 'a>>;
+
+/// Sends the requests of `AsyncClient`, see `Transport`.
+#[cfg(feature = "async")]
+pub trait AsyncTransport: fmt::Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_>;
+}
+
+#[cfg(feature = "async")]
+impl<T: AsyncTransport + ?Sized> AsyncTransport for Arc<T> {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        (**self).send(request)
+    }
+}
+
+/// Used in place of a transport that could not be built from the `ConnectionConfig`,
+/// e.g. because of an invalid proxy URL. Every request fails with the reason.
+#[derive(Debug)]
+pub(crate) struct InvalidTransport {
+    reason: String,
+}
+
+impl InvalidTransport {
+    pub(crate) fn new(reason: String) -> Self {
+        InvalidTransport { reason }
+    }
+}
+
+impl Transport for InvalidTransport {
+    fn send(&self, _request: HttpRequest) -> Result<HttpResponse> {
+        bail!(format!("Invalid connection settings: {}", self.reason))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for InvalidTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move { Transport::send(self, request) })
+    }
+}
+
+/// `Transport` on top of `reqwest::blocking::Client`.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Self {
+        Self::with_connection(&ConnectionConfig::default()).unwrap()
+    }
+
+    pub fn with_connection(connection: &ConnectionConfig) -> Result<Self> {
+        let mut builder = reqwest::blocking::Client::builder().pool_idle_timeout(None);
+        if let Some(connect_timeout) = connection.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(timeout) = connection.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = &connection.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy.as_str())?);
+        }
+        if let Some(local_address) = connection.local_address {
+            builder = builder.local_address(local_address);
+        }
+        Ok(Self::from_client(builder.build()?))
+    }
+
+    pub fn from_client(client: reqwest::blocking::Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let mut builder = self
+            .client
+            .request(request.method, request.url.as_str())
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send()?;
+        Ok(HttpResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes()?.to_vec(),
+        })
+    }
+}
+
+/// `AsyncTransport` on top of `reqwest::Client`.
+#[cfg(feature = "async")]
+#[derive(Debug, Clone)]
+pub struct AsyncReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl AsyncReqwestTransport {
+    pub fn new() -> Self {
+        Self::with_connection(&ConnectionConfig::default()).unwrap()
+    }
+
+    pub fn with_connection(connection: &ConnectionConfig) -> Result<Self> {
+        let mut builder = reqwest::Client::builder().pool_idle_timeout(None);
+        if let Some(connect_timeout) = connection.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(timeout) = connection.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = &connection.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy.as_str())?);
+        }
+        if let Some(local_address) = connection.local_address {
+            builder = builder.local_address(local_address);
+        }
+        Ok(Self::from_client(builder.build()?))
+    }
+
+    pub fn from_client(client: reqwest::Client) -> Self {
+        AsyncReqwestTransport { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Default for AsyncReqwestTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for AsyncReqwestTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url.as_str())
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            Ok(HttpResponse {
+                status,
+                headers,
+                body: response.bytes().await?.to_vec(),
+            })
+        })
+    }
+}
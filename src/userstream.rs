@@ -1,28 +1,43 @@
 use crate::model::{Success, UserDataStream};
 use crate::client::Client;
+#[cfg(feature = "async")]
+use crate::client::AsyncClient;
 use crate::errors::Result;
 use crate::api::API;
 use crate::api::Spot;
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncUserStream")
+)]
 #[derive(Clone)]
 pub struct UserStream {
     pub client: Client,
     pub recv_window: u64,
 }
 
+#[maybe_async_cfg::maybe(
+    idents(Client(sync, async = "AsyncClient")),
+    sync(keep_self),
+    async(feature = "async", self = "AsyncUserStream")
+)]
 impl UserStream {
     // User Stream
-    pub fn start(&self) -> Result<UserDataStream> {
-        self.client.post(API::Spot(Spot::UserDataStream))
+    pub async fn start(&self) -> Result<UserDataStream> {
+        self.client.post(API::Spot(Spot::UserDataStream)).await
     }
 
     // Current open orders on a symbol
-    pub fn keep_alive(&self, listen_key: &str) -> Result<Success> {
-        self.client.put(API::Spot(Spot::UserDataStream), listen_key)
+    pub async fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::Spot(Spot::UserDataStream), listen_key)
+            .await
     }
 
-    pub fn close(&self, listen_key: &str) -> Result<Success> {
+    pub async fn close(&self, listen_key: &str) -> Result<Success> {
         self.client
             .delete(API::Spot(Spot::UserDataStream), listen_key)
+            .await
     }
 }
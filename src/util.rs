@@ -2,7 +2,14 @@ use crate::errors::Result;
 use std::collections::BTreeMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 use error_chain::bail;
+use reqwest::header::{HeaderMap, HeaderValue};
 use serde_json::Value;
+use url::form_urlencoded::byte_serialize;
+
+/// Query parameters whose value is never logged.
+const SECRET_PARAMS: [&str; 2] = ["signature", "listenKey"];
+
+const REDACTED: &str = "<redacted>";
 
 pub fn build_request(parameters: BTreeMap<String, String>) -> String {
     let mut request = String::new();
@@ -14,6 +21,17 @@ pub fn build_request(parameters: BTreeMap<String, String>) -> String {
     request
 }
 
+/// Encode `symbols` as the JSON array taken by the `symbols` query parameter,
+/// e.g. `["BTCUSDT","BNBUSDT"]`, percent-encoded.
+pub fn build_symbols_param<S: AsRef<str>>(symbols: &[S]) -> String {
+    let symbols: Vec<String> = symbols
+        .iter()
+        .map(|symbol| format!("\"{}\"", symbol.as_ref()))
+        .collect();
+    let symbols = format!("[{}]", symbols.join(","));
+    byte_serialize(symbols.as_bytes()).collect()
+}
+
 pub fn build_signed_request(
     parameters: BTreeMap<String, String>, recv_window: u64,
 ) -> Result<String> {
@@ -41,7 +59,7 @@ pub fn to_f64(v: &Value) -> f64 {
     v.as_str().unwrap().parse().unwrap()
 }
 
-fn get_timestamp(start: SystemTime) -> Result<u64> {
+pub(crate) fn get_timestamp(start: SystemTime) -> Result<u64> {
     let since_epoch = start.duration_since(UNIX_EPOCH)?;
     Ok(since_epoch.as_secs() * 1000 + u64::from(since_epoch.subsec_nanos()) / 1_000_000)
 }
@@ -52,11 +70,7 @@ pub fn is_start_time_valid(start_time: &u64) -> bool {
         .unwrap()
         .as_secs();
 
-    if start_time > &current_time {
-        false
-    } else {
-        true
-    }
+    start_time <= &current_time
 }
 
 pub fn generate_uuid22() -> String {
@@ -73,3 +87,57 @@ pub fn uuid_spot() -> String {
 pub fn uuid_futures() -> String {
     format!("x-Cb7ytekJ{}", generate_uuid22())
 }
+
+/// `request` with the values of `signature` and `listenKey` masked, for logging.
+pub(crate) fn redact_query(request: &str) -> String {
+    request
+        .split('&')
+        .map(|param| match param.split_once('=') {
+            Some((name, _)) if SECRET_PARAMS.contains(&name) => format!("{}={}", name, REDACTED),
+            _ => param.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// `url` with its query redacted, and the listen keys of user data streams masked.
+pub(crate) fn redact_url(url: &str) -> String {
+    let (path, query) = match url.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (url, None),
+    };
+    let mut redacted = redact_stream_names(path);
+    if let Some(query) = query {
+        redacted.push('?');
+        redacted.push_str(&redact_query(&redact_stream_names(query)));
+    }
+    redacted
+}
+
+// stream names always carry an `@` (`btcusdt@trade`), listen keys are long and don't
+pub(crate) fn is_listen_key(name: &str) -> bool {
+    name.len() >= 32 && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn redact_stream_names(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            let name = segment.rsplit('=').next().unwrap_or(segment);
+            if is_listen_key(name) {
+                segment.replace(name, REDACTED)
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// `headers` with the API key masked, for logging.
+pub(crate) fn redact_headers(headers: &HeaderMap) -> HeaderMap {
+    let mut redacted = headers.clone();
+    if let Some(api_key) = redacted.get_mut("x-mbx-apikey") {
+        *api_key = HeaderValue::from_static(REDACTED);
+    }
+    redacted
+}
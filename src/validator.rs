@@ -0,0 +1,482 @@
+use std::fmt;
+
+use crate::account::OrderSide;
+use crate::errors::{ErrorKind, Result};
+use crate::futures::model::Symbol as FuturesSymbol;
+use crate::model::{ApiNumber, Filters, Number, Symbol};
+
+/// Why an order would be rejected by one of the symbol's filters.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterViolation {
+    /// PRICE_FILTER
+    PriceBelowMin { price: Number, min_price: Number },
+    /// PRICE_FILTER
+    PriceAboveMax { price: Number, max_price: Number },
+    /// PRICE_FILTER
+    PriceNotOnTick { price: Number, tick_size: Number },
+    /// LOT_SIZE or MARKET_LOT_SIZE
+    QuantityBelowMin { quantity: Number, min_qty: Number },
+    /// LOT_SIZE or MARKET_LOT_SIZE
+    QuantityAboveMax { quantity: Number, max_qty: Number },
+    /// LOT_SIZE or MARKET_LOT_SIZE
+    QuantityNotOnStep { quantity: Number, step_size: Number },
+    /// MIN_NOTIONAL or NOTIONAL
+    NotionalBelowMin {
+        notional: Number,
+        min_notional: Number,
+    },
+    /// NOTIONAL
+    NotionalAboveMax {
+        notional: Number,
+        max_notional: Number,
+    },
+    /// PERCENT_PRICE or PERCENT_PRICE_BY_SIDE
+    PriceOutsidePercentBounds {
+        price: Number,
+        min_price: Number,
+        max_price: Number,
+    },
+}
+
+impl fmt::Display for FilterViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::PriceBelowMin { price, min_price } => {
+                write!(f, "price {} is below the minimum {}", price, min_price)
+            }
+            Self::PriceAboveMax { price, max_price } => {
+                write!(f, "price {} is above the maximum {}", price, max_price)
+            }
+            Self::PriceNotOnTick { price, tick_size } => {
+                write!(
+                    f,
+                    "price {} isn't a multiple of the tick size {}",
+                    price, tick_size
+                )
+            }
+            Self::QuantityBelowMin { quantity, min_qty } => {
+                write!(f, "quantity {} is below the minimum {}", quantity, min_qty)
+            }
+            Self::QuantityAboveMax { quantity, max_qty } => {
+                write!(f, "quantity {} is above the maximum {}", quantity, max_qty)
+            }
+            Self::QuantityNotOnStep {
+                quantity,
+                step_size,
+            } => write!(
+                f,
+                "quantity {} isn't a multiple of the step size {}",
+                quantity, step_size
+            ),
+            Self::NotionalBelowMin {
+                notional,
+                min_notional,
+            } => write!(
+                f,
+                "notional {} is below the minimum {}",
+                notional, min_notional
+            ),
+            Self::NotionalAboveMax {
+                notional,
+                max_notional,
+            } => write!(
+                f,
+                "notional {} is above the maximum {}",
+                notional, max_notional
+            ),
+            Self::PriceOutsidePercentBounds {
+                price,
+                min_price,
+                max_price,
+            } => write!(
+                f,
+                "price {} is outside of {} - {}",
+                price, min_price, max_price
+            ),
+        }
+    }
+}
+
+#[cfg(not(feature = "decimal"))]
+const ZERO: Number = 0.0;
+#[cfg(feature = "decimal")]
+const ZERO: Number = Number::ZERO;
+
+// A tick or step size, with the number of decimals it is written with.
+#[derive(Debug, Clone, Copy)]
+struct Increment {
+    size: Number,
+    decimals: usize,
+}
+
+impl Increment {
+    fn parse(value: &str) -> Result<Self> {
+        let decimals = match value.split_once('.') {
+            Some((_, fraction)) => fraction.trim_end_matches('0').len(),
+            None => 0,
+        };
+        Ok(Increment {
+            size: parse_number(value)?,
+            decimals,
+        })
+    }
+
+    // `floor` rounds towards zero, otherwise to the nearest multiple
+    #[cfg(not(feature = "decimal"))]
+    fn round(&self, value: Number, floor: bool) -> Number {
+        if self.size <= 0.0 {
+            return value;
+        }
+        let steps = value / self.size;
+        // absorbs the error of the division, e.g. 0.3 / 0.1 = 2.9999999999999996
+        let steps = if floor {
+            (steps + 1e-9).floor()
+        } else {
+            steps.round()
+        };
+        format!("{:.*}", self.decimals, steps * self.size)
+            .parse()
+            .unwrap_or(value)
+    }
+
+    #[cfg(feature = "decimal")]
+    fn round(&self, value: Number, floor: bool) -> Number {
+        if self.size <= ZERO {
+            return value;
+        }
+        let steps = value / self.size;
+        let steps = if floor {
+            steps.floor()
+        } else {
+            steps.round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero)
+        };
+        (steps * self.size).round_dp(self.decimals as u32)
+    }
+
+    #[cfg(not(feature = "decimal"))]
+    fn contains(&self, value: Number) -> bool {
+        self.size <= 0.0 || (self.round(value, false) - value).abs() <= self.size * 1e-9
+    }
+
+    #[cfg(feature = "decimal")]
+    fn contains(&self, value: Number) -> bool {
+        self.size <= ZERO || self.round(value, false) == value
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Range {
+    min: Number,
+    max: Number,
+    increment: Increment,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Notional {
+    min: Number,
+    max: Number,
+    apply_min_to_market: bool,
+    apply_max_to_market: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PercentPrice {
+    bid_up: Number,
+    bid_down: Number,
+    ask_up: Number,
+    ask_down: Number,
+}
+
+/// Rounds and checks orders against a symbol's filters, before they are sent.
+///
+/// Built from the spot or futures exchange information, see `Account::order_validator`
+/// and `FuturesAccount::order_validator`. Limits set to 0 by Binance are disabled.
+/// Checks needing the market price (market order notional and PERCENT_PRICE) are
+/// skipped without a `reference_price`: the average price on spot, the mark price
+/// on futures.
+#[derive(Debug, Clone)]
+pub struct OrderValidator {
+    symbol: String,
+    price: Option<Range>,
+    lot_size: Option<Range>,
+    market_lot_size: Option<Range>,
+    notional: Option<Notional>,
+    percent_price: Option<PercentPrice>,
+}
+
+impl OrderValidator {
+    pub fn new<S: Into<String>>(symbol: S, filters: &[Filters]) -> Result<Self> {
+        let mut validator = OrderValidator {
+            symbol: symbol.into(),
+            price: None,
+            lot_size: None,
+            market_lot_size: None,
+            notional: None,
+            percent_price: None,
+        };
+        for filter in filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    validator.price = Some(Range {
+                        min: parse_number(min_price)?,
+                        max: parse_number(max_price)?,
+                        increment: Increment::parse(tick_size)?,
+                    });
+                }
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    validator.lot_size = Some(Range {
+                        min: parse_number(min_qty)?,
+                        max: parse_number(max_qty)?,
+                        increment: Increment::parse(step_size)?,
+                    });
+                }
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    validator.market_lot_size = Some(Range {
+                        min: parse_number(min_qty)?,
+                        max: parse_number(max_qty)?,
+                        increment: Increment::parse(step_size)?,
+                    });
+                }
+                // spot names it minNotional, futures notional
+                Filters::MinNotional {
+                    notional,
+                    min_notional,
+                    apply_to_market,
+                    ..
+                } => {
+                    if let Some(min) = min_notional.as_ref().or(notional.as_ref()) {
+                        let apply_to_market = apply_to_market.unwrap_or(true);
+                        validator.notional = Some(Notional {
+                            min: parse_number(min)?,
+                            max: ZERO,
+                            apply_min_to_market: apply_to_market,
+                            apply_max_to_market: false,
+                        });
+                    }
+                }
+                Filters::Notional {
+                    min_notional,
+                    max_notional,
+                    apply_min_to_market,
+                    apply_max_to_market,
+                    ..
+                } => {
+                    validator.notional = Some(Notional {
+                        min: parse_or_zero(min_notional)?,
+                        max: parse_or_zero(max_notional)?,
+                        apply_min_to_market: apply_min_to_market.unwrap_or(true),
+                        apply_max_to_market: apply_max_to_market.unwrap_or(false),
+                    });
+                }
+                Filters::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    ..
+                } => {
+                    let (up, down) = (parse_number(multiplier_up)?, parse_number(multiplier_down)?);
+                    validator.percent_price = Some(PercentPrice {
+                        bid_up: up,
+                        bid_down: down,
+                        ask_up: up,
+                        ask_down: down,
+                    });
+                }
+                Filters::PercentPriceBySide {
+                    bid_multiplier_up,
+                    bid_multiplier_down,
+                    ask_multiplier_up,
+                    ask_multiplier_down,
+                    ..
+                } => {
+                    validator.percent_price = Some(PercentPrice {
+                        bid_up: parse_number(bid_multiplier_up)?,
+                        bid_down: parse_number(bid_multiplier_down)?,
+                        ask_up: parse_number(ask_multiplier_up)?,
+                        ask_down: parse_number(ask_multiplier_down)?,
+                    });
+                }
+                _ => (),
+            }
+        }
+        Ok(validator)
+    }
+
+    pub fn from_symbol(symbol: &Symbol) -> Result<Self> {
+        Self::new(symbol.symbol.clone(), &symbol.filters)
+    }
+
+    pub fn from_futures_symbol(symbol: &FuturesSymbol) -> Result<Self> {
+        Self::new(symbol.symbol.clone(), &symbol.filters)
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Rounds to the nearest multiple of the tick size.
+    pub fn round_price(&self, price: Number) -> Number {
+        match &self.price {
+            Some(range) => range.increment.round(price, false),
+            None => price,
+        }
+    }
+
+    /// Rounds down to a multiple of the LOT_SIZE step size.
+    pub fn round_quantity(&self, quantity: Number) -> Number {
+        match &self.lot_size {
+            Some(range) => range.increment.round(quantity, true),
+            None => quantity,
+        }
+    }
+
+    /// Rounds down to a multiple of the MARKET_LOT_SIZE step size, or of the LOT_SIZE one
+    /// when the former is disabled.
+    pub fn round_market_quantity(&self, quantity: Number) -> Number {
+        match &self.market_lot_size {
+            Some(range) if range.increment.size > ZERO => range.increment.round(quantity, true),
+            _ => self.round_quantity(quantity),
+        }
+    }
+
+    /// Checks a price, or a stop price, against PRICE_FILTER.
+    pub fn validate_price(&self, price: Number) -> Result<()> {
+        let range = match &self.price {
+            Some(range) => range,
+            None => return Ok(()),
+        };
+        if range.min > ZERO && price < range.min {
+            return self.violation(FilterViolation::PriceBelowMin {
+                price,
+                min_price: range.min,
+            });
+        }
+        if range.max > ZERO && price > range.max {
+            return self.violation(FilterViolation::PriceAboveMax {
+                price,
+                max_price: range.max,
+            });
+        }
+        if !range.increment.contains(price) {
+            return self.violation(FilterViolation::PriceNotOnTick {
+                price,
+                tick_size: range.increment.size,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks a quantity against LOT_SIZE, and MARKET_LOT_SIZE for market orders.
+    pub fn validate_quantity(&self, quantity: Number, market: bool) -> Result<()> {
+        self.validate_lot_size(self.lot_size.as_ref(), quantity)?;
+        if market {
+            self.validate_lot_size(self.market_lot_size.as_ref(), quantity)?;
+        }
+        Ok(())
+    }
+
+    /// Checks price * quantity against MIN_NOTIONAL and NOTIONAL.
+    pub fn validate_notional(&self, notional: Number, market: bool) -> Result<()> {
+        let limits = match &self.notional {
+            Some(limits) => limits,
+            None => return Ok(()),
+        };
+        if limits.min > ZERO && notional < limits.min && (!market || limits.apply_min_to_market) {
+            return self.violation(FilterViolation::NotionalBelowMin {
+                notional,
+                min_notional: limits.min,
+            });
+        }
+        if limits.max > ZERO && notional > limits.max && (!market || limits.apply_max_to_market) {
+            return self.violation(FilterViolation::NotionalAboveMax {
+                notional,
+                max_notional: limits.max,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks an order, `price` being `None` for market orders.
+    pub fn validate(
+        &self, side: &OrderSide, price: Option<Number>, quantity: Number,
+        reference_price: Option<Number>,
+    ) -> Result<()> {
+        let market = price.is_none();
+        if let Some(price) = price {
+            self.validate_price(price)?;
+        }
+        self.validate_quantity(quantity, market)?;
+        if let Some(price) = price.or(reference_price) {
+            self.validate_notional(price * quantity, market)?;
+        }
+        if let (Some(price), Some(reference_price), Some(percent)) =
+            (price, reference_price, &self.percent_price)
+        {
+            let (up, down) = match side {
+                OrderSide::Buy => (percent.bid_up, percent.bid_down),
+                OrderSide::Sell => (percent.ask_up, percent.ask_down),
+            };
+            let (min_price, max_price) = (reference_price * down, reference_price * up);
+            if price < min_price || price > max_price {
+                return self.violation(FilterViolation::PriceOutsidePercentBounds {
+                    price,
+                    min_price,
+                    max_price,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_lot_size(&self, range: Option<&Range>, quantity: Number) -> Result<()> {
+        let range = match range {
+            Some(range) => range,
+            None => return Ok(()),
+        };
+        if range.min > ZERO && quantity < range.min {
+            return self.violation(FilterViolation::QuantityBelowMin {
+                quantity,
+                min_qty: range.min,
+            });
+        }
+        if range.max > ZERO && quantity > range.max {
+            return self.violation(FilterViolation::QuantityAboveMax {
+                quantity,
+                max_qty: range.max,
+            });
+        }
+        if !range.increment.contains(quantity) {
+            return self.violation(FilterViolation::QuantityNotOnStep {
+                quantity,
+                step_size: range.increment.size,
+            });
+        }
+        Ok(())
+    }
+
+    fn violation(&self, violation: FilterViolation) -> Result<()> {
+        Err(ErrorKind::FilterViolation(self.symbol.clone(), violation).into())
+    }
+}
+
+fn parse_number(value: &str) -> Result<Number> {
+    Ok(Number::from_api_str(value)?)
+}
+
+fn parse_or_zero(value: &Option<String>) -> Result<Number> {
+    match value {
+        Some(value) => parse_number(value),
+        None => Ok(ZERO),
+    }
+}
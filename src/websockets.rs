@@ -1,5 +1,8 @@
 use crate::errors::Result;
-use crate::config::Config;
+use crate::cassette::{normalize_url, Cassette, CassetteMode};
+use crate::config::{Config, ConnectionConfig};
+use crate::connection::connect_websocket;
+use crate::util::redact_url;
 use crate::model::{
     AccountUpdateEvent, AggrTradesEvent, BalanceUpdateEvent, BookTickerEvent, DayTickerEvent,
     WindowTickerEvent, DepthOrderBookEvent, KlineEvent, OrderBook, OrderTradeEvent, TradeEvent,
@@ -8,9 +11,11 @@ use error_chain::bail;
 use url::Url;
 use serde::{Deserialize, Serialize};
 
+use std::collections::VecDeque;
+use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::net::TcpStream;
-use tungstenite::{connect, Message};
+use tungstenite::Message;
 use tungstenite::protocol::WebSocket;
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::handshake::client::Response;
@@ -55,6 +60,10 @@ pub enum WebsocketEvent {
 pub struct WebSockets<'a> {
     pub socket: Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)>,
     handler: Box<dyn FnMut(WebsocketEvent) -> Result<()> + 'a>,
+    cassette: Option<Arc<Cassette>>,
+    stream: String,
+    replay: Option<VecDeque<String>>,
+    connection: ConnectionConfig,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -83,14 +92,27 @@ impl<'a> WebSockets<'a> {
         WebSockets {
             socket: None,
             handler: Box::new(handler),
+            cassette: None,
+            stream: String::new(),
+            replay: None,
+            connection: ConnectionConfig::default(),
         }
     }
 
+    /// Record the frames received to `cassette`, or replay them from there.
+    pub fn set_cassette(&mut self, cassette: Arc<Cassette>) {
+        self.cassette = Some(cassette);
+    }
+
     pub fn connect(&mut self, subscription: &str) -> Result<()> {
         self.connect_wss(&WebsocketAPI::Default.params(subscription))
     }
 
     pub fn connect_with_config(&mut self, subscription: &str, config: &Config) -> Result<()> {
+        if let Some(cassette) = &config.cassette {
+            self.set_cassette(cassette.clone());
+        }
+        self.connection = config.connection.clone();
         self.connect_wss(&WebsocketAPI::Custom(config.ws_endpoint.clone()).params(subscription))
     }
 
@@ -99,19 +121,40 @@ impl<'a> WebSockets<'a> {
     }
 
     fn connect_wss(&mut self, wss: &str) -> Result<()> {
+        self.stream = normalize_url(wss)?;
+        if let Some(cassette) = &self.cassette {
+            if cassette.mode() == CassetteMode::Replay {
+                self.replay = Some(cassette.replay_frames(&self.stream).into());
+                tracing::info!(stream = %redact_url(&self.stream), "replaying websocket stream");
+                return Ok(());
+            }
+        }
+
         let url = Url::parse(wss)?;
-        match connect(url) {
+        match connect_websocket(&url, &self.connection) {
             Ok(answer) => {
+                tracing::info!(stream = %redact_url(&self.stream), "websocket connected");
                 self.socket = Some(answer);
                 Ok(())
             }
-            Err(e) => bail!(format!("Error during handshake {}", e)),
+            Err(e) => {
+                tracing::warn!(
+                    stream = %redact_url(&self.stream),
+                    error = %e,
+                    "websocket handshake failed"
+                );
+                bail!(format!("Error during handshake {}", e))
+            }
         }
     }
 
     pub fn disconnect(&mut self) -> Result<()> {
+        if self.replay.take().is_some() {
+            return Ok(());
+        }
         if let Some(ref mut socket) = self.socket {
             socket.0.close(None)?;
+            tracing::info!(stream = %redact_url(&self.stream), "websocket disconnected");
             return Ok(());
         }
         bail!("Not able to close the connection");
@@ -122,7 +165,17 @@ impl<'a> WebSockets<'a> {
     }
 
     pub fn handle_msg(&mut self, msg: &str) -> Result<()> {
-        let value: serde_json::Value = serde_json::from_str(msg)?;
+        let value: serde_json::Value = match serde_json::from_str(msg) {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::warn!(
+                    stream = %redact_url(&self.stream),
+                    error = %e,
+                    "invalid websocket message"
+                );
+                return Err(e.into());
+            }
+        };
 
         if let Some(data) = value.get("data") {
             self.handle_msg(&data.to_string())?;
@@ -146,6 +199,8 @@ impl<'a> WebSockets<'a> {
                 Events::DepthOrderBookEvent(v) => WebsocketEvent::DepthOrderBook(v),
             };
             (self.handler)(action)?;
+        } else {
+            tracing::debug!(stream = %redact_url(&self.stream), "unhandled websocket message");
         }
         Ok(())
     }
@@ -153,18 +208,42 @@ impl<'a> WebSockets<'a> {
     pub fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
         while running.load(Ordering::Relaxed) {
             if let Some(ref mut socket) = self.socket {
-                let message = socket.0.read_message()?;
+                let message = socket.0.read()?;
                 match message {
                     Message::Text(msg) => {
+                        if let Some(cassette) = &self.cassette {
+                            cassette.record_frame(&self.stream, &msg)?;
+                        }
                         if let Err(e) = self.handle_msg(&msg) {
+                            tracing::warn!(
+                                stream = %redact_url(&self.stream),
+                                error = %e,
+                                "failed to handle websocket message"
+                            );
                             bail!(format!("Error on handling stream message: {}", e));
                         }
                     }
                     Message::Ping(payload) => {
-                        socket.0.write_message(Message::Pong(payload)).unwrap();
+                        tracing::trace!(stream = %redact_url(&self.stream), "websocket ping");
+                        socket.0.send(Message::Pong(payload)).unwrap();
                     }
                     Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => (),
-                    Message::Close(e) => bail!(format!("Disconnected {:?}", e)),
+                    Message::Close(e) => {
+                        tracing::info!(
+                            stream = %redact_url(&self.stream),
+                            frame = ?e,
+                            "websocket closed by server"
+                        );
+                        bail!(format!("Disconnected {:?}", e))
+                    }
+                }
+            } else if let Some(replay) = self.replay.as_mut() {
+                // a replayed stream ends with its last recorded frame
+                let Some(msg) = replay.pop_front() else {
+                    return Ok(());
+                };
+                if let Err(e) = self.handle_msg(&msg) {
+                    bail!(format!("Error on handling stream message: {}", e));
                 }
             }
         }
@@ -1,7 +1,11 @@
+mod common;
+
 use binance::api::*;
 use binance::config::*;
 use binance::account::*;
+use binance::errors::*;
 use binance::model::*;
+use common::*;
 
 #[cfg(test)]
 mod tests {
@@ -37,18 +41,28 @@ mod tests {
         assert!(account.can_trade);
         assert!(account.can_withdraw);
         assert!(account.can_deposit);
+        let commission_rates = account.commission_rates.unwrap();
+        assert_number(commission_rates.maker, "0.0015");
+        assert_number(commission_rates.seller, "0.0");
+        assert!(!account.brokered);
+        assert!(!account.require_self_trade_prevention);
+        assert!(!account.prevent_sor);
+        assert_eq!(account.update_time, 123456789);
+        assert_eq!(account.account_type, "SPOT");
+        assert_eq!(account.permissions, vec!["SPOT"]);
+        assert_eq!(account.uid, Some(354937868));
 
         assert!(!account.balances.is_empty());
 
         let first_balance = &account.balances[0];
         assert_eq!(first_balance.asset, "BTC");
-        assert_eq!(first_balance.free, "4723846.89208129");
-        assert_eq!(first_balance.locked, "0.00000000");
+        assert_eq!(first_balance.free.to_string(), "4723846.89208129");
+        assert_eq!(first_balance.locked.to_string(), "0.00000000");
 
         let second_balance = &account.balances[1];
         assert_eq!(second_balance.asset, "LTC");
-        assert_eq!(second_balance.free, "4763368.68006011");
-        assert_eq!(second_balance.locked, "0.00000000");
+        assert_eq!(second_balance.free.to_string(), "4763368.68006011");
+        assert_eq!(second_balance.locked.to_string(), "0.00000000");
     }
 
     #[test]
@@ -73,8 +87,8 @@ mod tests {
         mock_get_account.assert();
 
         assert_eq!(balance.asset, "BTC");
-        assert_eq!(balance.free, "4723846.89208129");
-        assert_eq!(balance.locked, "0.00000000");
+        assert_eq!(balance.free.to_string(), "4723846.89208129");
+        assert_eq!(balance.locked.to_string(), "0.00000000");
     }
 
     #[test]
@@ -105,20 +119,20 @@ mod tests {
         assert_eq!(open_order.order_id, 1);
         assert_eq!(open_order.order_list_id, -1);
         assert_eq!(open_order.client_order_id, "myOrder1");
-        assert!(approx_eq!(f64, open_order.price, 0.1, ulps = 2));
-        assert_eq!(open_order.orig_qty, "1.0");
-        assert_eq!(open_order.executed_qty, "0.0");
-        assert_eq!(open_order.cummulative_quote_qty, "0.0");
+        assert_number(open_order.price, "0.1");
+        assert_eq!(open_order.orig_qty.to_string(), "1.0");
+        assert_eq!(open_order.executed_qty.to_string(), "0.0");
+        assert_eq!(open_order.cummulative_quote_qty.to_string(), "0.0");
         assert_eq!(open_order.status, "NEW");
         assert_eq!(open_order.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(open_order.type_name, "LIMIT");
         assert_eq!(open_order.side, "BUY");
-        assert!(approx_eq!(f64, open_order.stop_price, 0.0, ulps = 2));
-        assert_eq!(open_order.iceberg_qty, "0.0");
+        assert_number(open_order.stop_price, "0.0");
+        assert_eq!(open_order.iceberg_qty.to_string(), "0.0");
         assert_eq!(open_order.time, 1499827319559);
         assert_eq!(open_order.update_time, 1499827319559);
         assert!(open_order.is_working);
-        assert_eq!(open_order.orig_quote_order_qty, "0.000000");
+        assert_eq!(open_order.orig_quote_order_qty.to_string(), "0.000000");
     }
 
     #[test]
@@ -147,20 +161,20 @@ mod tests {
         assert_eq!(open_order.order_id, 1);
         assert_eq!(open_order.order_list_id, -1);
         assert_eq!(open_order.client_order_id, "myOrder1");
-        assert!(approx_eq!(f64, open_order.price, 0.1, ulps = 2));
-        assert_eq!(open_order.orig_qty, "1.0");
-        assert_eq!(open_order.executed_qty, "0.0");
-        assert_eq!(open_order.cummulative_quote_qty, "0.0");
+        assert_number(open_order.price, "0.1");
+        assert_eq!(open_order.orig_qty.to_string(), "1.0");
+        assert_eq!(open_order.executed_qty.to_string(), "0.0");
+        assert_eq!(open_order.cummulative_quote_qty.to_string(), "0.0");
         assert_eq!(open_order.status, "NEW");
         assert_eq!(open_order.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(open_order.type_name, "LIMIT");
         assert_eq!(open_order.side, "BUY");
-        assert!(approx_eq!(f64, open_order.stop_price, 0.0, ulps = 2));
-        assert_eq!(open_order.iceberg_qty, "0.0");
+        assert_number(open_order.stop_price, "0.0");
+        assert_eq!(open_order.iceberg_qty.to_string(), "0.0");
         assert_eq!(open_order.time, 1499827319559);
         assert_eq!(open_order.update_time, 1499827319559);
         assert!(open_order.is_working);
-        assert_eq!(open_order.orig_quote_order_qty, "0.000000");
+        assert_eq!(open_order.orig_quote_order_qty.to_string(), "0.000000");
     }
 
     #[test]
@@ -236,20 +250,76 @@ mod tests {
         assert_eq!(order_status.order_id, 1);
         assert_eq!(order_status.order_list_id, -1);
         assert_eq!(order_status.client_order_id, "myOrder1");
-        assert!(approx_eq!(f64, order_status.price, 0.1, ulps = 2));
-        assert_eq!(order_status.orig_qty, "1.0");
-        assert_eq!(order_status.executed_qty, "0.0");
-        assert_eq!(order_status.cummulative_quote_qty, "0.0");
+        assert_number(order_status.price, "0.1");
+        assert_eq!(order_status.orig_qty.to_string(), "1.0");
+        assert_eq!(order_status.executed_qty.to_string(), "0.0");
+        assert_eq!(order_status.cummulative_quote_qty.to_string(), "0.0");
         assert_eq!(order_status.status, "NEW");
         assert_eq!(order_status.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(order_status.type_name, "LIMIT");
         assert_eq!(order_status.side, "BUY");
-        assert!(approx_eq!(f64, order_status.stop_price, 0.0, ulps = 2));
-        assert_eq!(order_status.iceberg_qty, "0.0");
+        assert_number(order_status.stop_price, "0.0");
+        assert_eq!(order_status.iceberg_qty.to_string(), "0.0");
         assert_eq!(order_status.time, 1499827319559);
         assert_eq!(order_status.update_time, 1499827319559);
         assert!(order_status.is_working);
-        assert_eq!(order_status.orig_quote_order_qty, "0.000000");
+        assert_eq!(order_status.orig_quote_order_qty.to_string(), "0.000000");
+    }
+
+    #[test]
+    fn order_status_with_client_id() {
+        let mut server = Server::new();
+        let mock_order_status = server
+            .mock("GET", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "origClientOrderId=myOrder1&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_status.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_status: Order = account
+            .order_status_with_client_id("LTCBTC", "myOrder1")
+            .unwrap();
+
+        mock_order_status.assert();
+
+        assert_eq!(order_status.order_id, 1);
+        assert_eq!(order_status.client_order_id, "myOrder1");
+    }
+
+    #[test]
+    fn get_all_orders() {
+        let mut server = Server::new();
+        let mock_all_orders = server
+            .mock("GET", "/api/v3/allOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "endTime=1499913719559&limit=10&recvWindow=1234&startTime=1499827319559&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/all_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let orders: Vec<Order> = account
+            .get_all_orders("LTCBTC", None, Some(1499827319559), Some(1499913719559), 10)
+            .unwrap();
+
+        mock_all_orders.assert();
+
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].symbol, "LTCBTC");
+        assert_eq!(orders[0].order_id, 1);
+        assert_eq!(orders[0].status, "NEW");
     }
 
     #[test]
@@ -288,7 +358,7 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        let transaction: Transaction = account.limit_buy("LTCBTC", 1, 0.1).unwrap();
+        let transaction: Transaction = account.limit_buy("LTCBTC", 1, number("0.1")).unwrap();
 
         mock_limit_buy.assert();
 
@@ -297,15 +367,10 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
+        assert_number(transaction.price, "0.1");
+        assert_number(transaction.orig_qty, "1.0");
+        assert_number(transaction.executed_qty, "1.0");
+        assert_number(transaction.cummulative_quote_qty, "0.0");
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "LIMIT");
@@ -326,7 +391,7 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        account.test_limit_buy("LTCBTC", 1, 0.1).unwrap();
+        account.test_limit_buy("LTCBTC", 1, number("0.1")).unwrap();
 
         mock_test_limit_buy.assert();
     }
@@ -345,7 +410,7 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        let transaction: Transaction = account.limit_sell("LTCBTC", 1, 0.1).unwrap();
+        let transaction: Transaction = account.limit_sell("LTCBTC", 1, number("0.1")).unwrap();
 
         mock_limit_sell.assert();
 
@@ -354,15 +419,10 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
+        assert_number(transaction.price, "0.1");
+        assert_number(transaction.orig_qty, "1.0");
+        assert_number(transaction.executed_qty, "1.0");
+        assert_number(transaction.cummulative_quote_qty, "0.0");
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "LIMIT");
@@ -383,7 +443,7 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        account.test_limit_sell("LTCBTC", 1, 0.1).unwrap();
+        account.test_limit_sell("LTCBTC", 1, number("0.1")).unwrap();
 
         mock_test_limit_sell.assert();
     }
@@ -415,15 +475,10 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
+        assert_number(transaction.price, "0.1");
+        assert_number(transaction.orig_qty, "1.0");
+        assert_number(transaction.executed_qty, "1.0");
+        assert_number(transaction.cummulative_quote_qty, "0.0");
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "MARKET");
@@ -467,7 +522,7 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        match account.market_buy_using_quote_quantity("BNBBTC", 0.002) {
+        match account.market_buy_using_quote_quantity("BNBBTC", number("0.002")) {
             Ok(answer) => {
                 assert!(answer.order_id == 1);
             }
@@ -492,7 +547,7 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         account
-            .test_market_buy_using_quote_quantity("BNBBTC", 0.002)
+            .test_market_buy_using_quote_quantity("BNBBTC", number("0.002"))
             .unwrap();
 
         mock_test_market_buy_using_quote_quantity.assert();
@@ -525,15 +580,10 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
+        assert_number(transaction.price, "0.1");
+        assert_number(transaction.orig_qty, "1.0");
+        assert_number(transaction.executed_qty, "1.0");
+        assert_number(transaction.cummulative_quote_qty, "0.0");
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "MARKET");
@@ -577,7 +627,7 @@ mod tests {
             .set_recv_window(1234);
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        match account.market_sell_using_quote_quantity("BNBBTC", 0.002) {
+        match account.market_sell_using_quote_quantity("BNBBTC", number("0.002")) {
             Ok(answer) => {
                 assert!(answer.order_id == 1);
             }
@@ -602,7 +652,7 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         account
-            .test_market_sell_using_quote_quantity("BNBBTC", 0.002)
+            .test_market_sell_using_quote_quantity("BNBBTC", number("0.002"))
             .unwrap();
 
         mock_test_market_sell_using_quote_quantity.assert();
@@ -636,7 +686,7 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         let transaction: Transaction = account
-            .stop_limit_buy_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
+            .stop_limit_buy_order("LTCBTC", 1, number("0.1"), number("0.09"), TimeInForce::GTC)
             .unwrap();
 
         mock_stop_limit_buy_order.assert();
@@ -646,16 +696,11 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
+        assert_number(transaction.price, "0.1");
+        assert_number(transaction.orig_qty, "1.0");
+        assert_number(transaction.executed_qty, "1.0");
+        assert_number(transaction.cummulative_quote_qty, "0.0");
+        assert_number(transaction.stop_price, "0.09");
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
@@ -677,7 +722,7 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         account
-            .test_stop_limit_buy_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
+            .test_stop_limit_buy_order("LTCBTC", 1, number("0.1"), number("0.09"), TimeInForce::GTC)
             .unwrap();
 
         mock_test_stop_limit_buy_order.assert();
@@ -698,7 +743,7 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         let transaction: Transaction = account
-            .stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
+            .stop_limit_sell_order("LTCBTC", 1, number("0.1"), number("0.09"), TimeInForce::GTC)
             .unwrap();
 
         mock_stop_limit_sell_order.assert();
@@ -708,16 +753,11 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
+        assert_number(transaction.price, "0.1");
+        assert_number(transaction.orig_qty, "1.0");
+        assert_number(transaction.executed_qty, "1.0");
+        assert_number(transaction.cummulative_quote_qty, "0.0");
+        assert_number(transaction.stop_price, "0.09");
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
@@ -739,7 +779,13 @@ mod tests {
         let account: Account = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
         account
-            .test_stop_limit_sell_order("LTCBTC", 1, 0.1, 0.09, TimeInForce::GTC)
+            .test_stop_limit_sell_order(
+                "LTCBTC",
+                1,
+                number("0.1"),
+                number("0.09"),
+                TimeInForce::GTC,
+            )
             .unwrap();
 
         mock_test_stop_limit_sell_order.assert();
@@ -763,7 +809,7 @@ mod tests {
             .custom_order(
                 "LTCBTC",
                 1,
-                0.1,
+                number("0.1"),
                 None,
                 OrderSide::Buy,
                 OrderType::Market,
@@ -779,16 +825,11 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
+        assert_number(transaction.price, "0.1");
+        assert_number(transaction.orig_qty, "1.0");
+        assert_number(transaction.executed_qty, "1.0");
+        assert_number(transaction.cummulative_quote_qty, "0.0");
+        assert_number(transaction.stop_price, "0.09");
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
@@ -813,7 +854,7 @@ mod tests {
             .test_custom_order(
                 "LTCBTC",
                 1,
-                0.1,
+                number("0.1"),
                 None,
                 OrderSide::Buy,
                 OrderType::Market,
@@ -852,6 +893,418 @@ mod tests {
         assert_eq!(cancelled_order.client_order_id.unwrap(), "cancelMyOrder1");
     }
 
+    #[test]
+    fn place_order() {
+        let mut server = Server::new();
+        let mock_place_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "icebergQty=0.5&newClientOrderId=myOrder1&newOrderRespType=FULL&price=0.1&quantity=1&recvWindow=1234&selfTradePreventionMode=EXPIRE_TAKER&side=BUY&stopPrice=0.09&strategyId=1&strategyType=1000000&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=STOP_LOSS_LIMIT".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::StopLossLimit)
+            .set_quantity(1)
+            .set_price(number("0.1"))
+            .set_stop_price(number("0.09"))
+            .set_time_in_force(TimeInForce::GTC)
+            .set_iceberg_qty(number("0.5"))
+            .set_new_client_order_id("myOrder1")
+            .set_strategy_id(1)
+            .set_strategy_type(1_000_000)
+            .set_self_trade_prevention_mode(SelfTradePreventionMode::ExpireTaker)
+            .set_new_order_resp_type(NewOrderResponseType::Full)
+            .build()
+            .unwrap();
+        let transaction: Transaction = account.place_order(order).unwrap();
+
+        mock_place_order.assert();
+
+        assert_eq!(transaction.symbol, "LTCBTC");
+        assert_eq!(transaction.order_id, 1);
+    }
+
+    #[test]
+    fn place_order_ack() {
+        let mut server = Server::new();
+        let mock_place_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newClientOrderId=.*&newOrderRespType=ACK&quoteOrderQty=10&recvWindow=1234&side=SELL&symbol=BTCUSDT&timestamp=\\d+&type=MARKET".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/place_order_ack.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Sell, OrderType::Market)
+            .set_quote_order_qty(10)
+            .set_new_order_resp_type(NewOrderResponseType::Ack)
+            .build()
+            .unwrap();
+        let transaction: Transaction = account.place_order(order).unwrap();
+
+        mock_place_order.assert();
+
+        assert_eq!(transaction.order_id, 28);
+        assert_eq!(transaction.transact_time, 1507725176595);
+        assert!(transaction.fills.is_none());
+    }
+
+    #[test]
+    fn test_place_order() {
+        let mut server = Server::new();
+        let mock_test_place_order = server
+            .mock("POST", "/api/v3/order/test")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newClientOrderId=.*&quantity=1&recvWindow=1234&side=SELL&symbol=LTCBTC&timestamp=\\d+&trailingDelta=100&type=TAKE_PROFIT".into(),
+            ))
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("LTCBTC", OrderSide::Sell, OrderType::TakeProfit)
+            .set_quantity(1)
+            .set_trailing_delta(100)
+            .build()
+            .unwrap();
+        account.test_place_order(order).unwrap();
+
+        mock_test_place_order.assert();
+    }
+
+    #[test]
+    fn place_sor_order() {
+        let mut server = Server::new();
+        let mock_place_sor_order = server
+            .mock("POST", "/api/v3/sor/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newClientOrderId=.*&price=31000&quantity=0.5&recvWindow=1234&side=BUY&symbol=BTCUSDT&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/sor_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .set_quantity(number("0.5"))
+            .set_price(31000)
+            .set_time_in_force(TimeInForce::GTC)
+            .build()
+            .unwrap();
+        let transaction = account.place_sor_order(order).unwrap();
+
+        mock_place_sor_order.assert();
+
+        assert_eq!(transaction.order_id, 2);
+        assert_eq!(transaction.order_list_id, Some(-1));
+        assert_eq!(transaction.status, "FILLED");
+        assert_number(transaction.cummulative_quote_qty, "14000.0");
+        assert_eq!(transaction.working_floor.as_deref(), Some("SOR"));
+        assert!(transaction.used_sor);
+
+        let fills = transaction.fills.unwrap();
+        assert_eq!(
+            fills[0].match_type.as_deref(),
+            Some("ONE_PARTY_TRADE_REPORT")
+        );
+        assert_number(fills[0].price, "28000.0");
+        assert_eq!(fills[0].trade_id, -1);
+        assert_eq!(fills[0].alloc_id, Some(0));
+    }
+
+    #[test]
+    fn test_place_sor_order() {
+        let mut server = Server::new();
+        let mock_test_place_sor_order = server
+            .mock("POST", "/api/v3/sor/order/test")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newClientOrderId=.*&quantity=0.5&recvWindow=1234&side=SELL&symbol=BTCUSDT&timestamp=\\d+&type=MARKET".into(),
+            ))
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Sell, OrderType::Market)
+            .set_quantity(number("0.5"))
+            .build()
+            .unwrap();
+        account.test_place_sor_order(order).unwrap();
+
+        mock_test_place_sor_order.assert();
+
+        // SOR takes neither quote quantities nor stop orders, nothing is sent
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Sell, OrderType::Market)
+            .set_quote_order_qty(100)
+            .build()
+            .unwrap();
+        assert!(account.test_place_sor_order(order).is_err());
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Sell, OrderType::StopLoss)
+            .set_quantity(number("0.5"))
+            .set_stop_price(25000)
+            .build()
+            .unwrap();
+        assert!(account.test_place_sor_order(order).is_err());
+        mock_test_place_sor_order.assert();
+    }
+
+    #[test]
+    fn spot_order_request_validation() {
+        let limit = || {
+            SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::Limit)
+                .set_quantity(1)
+                .set_price(number("0.1"))
+        };
+        assert!(limit().validate().is_err());
+        assert!(limit().build().is_err());
+        assert!(
+            limit()
+                .set_time_in_force(TimeInForce::GTC)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            limit()
+                .set_time_in_force(TimeInForce::IOC)
+                .set_iceberg_qty(number("0.5"))
+                .validate()
+                .is_err()
+        );
+        assert!(
+            limit()
+                .set_time_in_force(TimeInForce::GTC)
+                .set_stop_price(number("0.09"))
+                .validate()
+                .is_err()
+        );
+
+        let market = || SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::Market);
+        assert!(market().validate().is_err());
+        assert!(market().set_quantity(1).validate().is_ok());
+        assert!(
+            market()
+                .set_quantity(1)
+                .set_quote_order_qty(10)
+                .validate()
+                .is_err()
+        );
+
+        let stop_loss =
+            SpotOrderRequest::new("LTCBTC", OrderSide::Sell, OrderType::StopLoss).set_quantity(1);
+        assert!(stop_loss.validate().is_err());
+
+        let limit_maker = SpotOrderRequest::new("LTCBTC", OrderSide::Sell, OrderType::LimitMaker)
+            .set_quantity(1)
+            .set_price(number("0.2"))
+            .set_strategy_type(10);
+        assert!(limit_maker.validate().is_err());
+    }
+
+    #[test]
+    fn cancel_replace() {
+        let mut server = Server::new();
+        let mock_cancel_replace = server
+            .mock("POST", "/api/v3/order/cancelReplace")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "cancelOrigClientOrderId=myOrder1&cancelReplaceMode=STOP_ON_FAILURE&cancelRestrictions=ONLY_NEW&newClientOrderId=myOrder2&price=0.11&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_replace.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::Limit)
+            .set_quantity(1)
+            .set_price(number("0.11"))
+            .set_time_in_force(TimeInForce::GTC)
+            .set_new_client_order_id("myOrder2")
+            .build()
+            .unwrap();
+        let request = CancelReplaceRequest::new(order, CancelReplaceMode::StopOnFailure)
+            .set_cancel_orig_client_order_id("myOrder1")
+            .set_cancel_restrictions(CancelRestrictions::OnlyNew);
+        let response = account.cancel_replace(request).unwrap();
+
+        mock_cancel_replace.assert();
+
+        assert_eq!(response.cancel_result, CancelReplaceStatus::Success);
+        assert_eq!(response.new_order_result, CancelReplaceStatus::Success);
+        match response.cancel_response {
+            CancelReplaceResponse::Accepted(canceled) => {
+                assert_eq!(canceled.order_id, Some(1))
+            }
+            CancelReplaceResponse::Rejected(error) => panic!("{:?}", error),
+        }
+        match response.new_order_response {
+            Some(CancelReplaceResponse::Accepted(transaction)) => {
+                assert_eq!(transaction.order_id, 2);
+                assert_eq!(transaction.client_order_id, "myOrder2");
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn cancel_replace_partial_failure() {
+        let mut server = Server::new();
+        let mock_cancel_replace = server
+            .mock("POST", "/api/v3/order/cancelReplace")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_status(400)
+            .match_query(Matcher::Regex(
+                "cancelOrderId=1&cancelReplaceMode=ALLOW_FAILURE".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_replace_partial_failure.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::LimitMaker)
+            .set_quantity(1)
+            .set_price(number("0.2"))
+            .build()
+            .unwrap();
+        let request = CancelReplaceRequest::new(order, CancelReplaceMode::AllowFailure)
+            .set_cancel_order_id(1);
+        let error = account.cancel_replace(request).unwrap_err();
+
+        mock_cancel_replace.assert();
+
+        let (response, outcome) = match error.kind() {
+            ErrorKind::CancelReplaceFailed(response, outcome) => (response, outcome),
+            other => panic!("{:?}", other),
+        };
+        assert_eq!(
+            response.error_code(),
+            BinanceErrorCode::CancelReplacePartiallyFailed
+        );
+        assert_eq!(outcome.cancel_result, CancelReplaceStatus::Success);
+        assert_eq!(outcome.new_order_result, CancelReplaceStatus::Failure);
+        match &outcome.new_order_response {
+            Some(CancelReplaceResponse::Rejected(error)) => assert_eq!(error.code, -2010),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn cancel_replace_unexpected_data() {
+        let mut server = Server::new();
+        let mock_cancel_replace = server
+            .mock("POST", "/api/v3/order/cancelReplace")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_status(400)
+            .match_query(Matcher::Any)
+            .with_body("{\"code\":-2022,\"msg\":\"Order cancel-replace failed.\",\"data\":{}}")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::Market)
+            .set_quantity(1)
+            .build()
+            .unwrap();
+        let request = CancelReplaceRequest::new(order, CancelReplaceMode::StopOnFailure)
+            .set_cancel_order_id(1);
+        let error = account.cancel_replace(request).unwrap_err();
+
+        mock_cancel_replace.assert();
+
+        // Binance's error is kept when the outcome can't be read
+        assert!(matches!(error.kind(), ErrorKind::BinanceError(_)));
+        assert_eq!(
+            error.binance_error().unwrap().error_code(),
+            BinanceErrorCode::CancelReplaceFailed
+        );
+    }
+
+    #[test]
+    fn amend_order_keep_priority() {
+        let mut server = Server::new();
+        let mock_amend = server
+            .mock("PUT", "/api/v3/order/amend/keepPriority")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newQty=0.5&orderId=1&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/amend_order_keep_priority.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let amendment = account
+            .amend_order_keep_priority("LTCBTC", 1, number("0.5"))
+            .unwrap();
+
+        mock_amend.assert();
+
+        assert_eq!(amendment.execution_id, 75);
+        assert_eq!(amendment.amended_order.order_id, 1);
+        assert_number(amendment.amended_order.qty, "0.5");
+        assert_eq!(amendment.amended_order.client_order_id, "myOrder1Amended");
+    }
+
+    #[test]
+    fn cancel_order_with_client_id() {
+        let mut server = Server::new();
+        let mock_cancel_order = server
+            .mock("DELETE", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "origClientOrderId=myOrder1&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let cancelled_order = account
+            .cancel_order_with_client_id("LTCBTC", "myOrder1")
+            .unwrap();
+
+        mock_cancel_order.assert();
+
+        assert_eq!(cancelled_order.orig_client_order_id.unwrap(), "myOrder1");
+        assert_eq!(cancelled_order.order_id.unwrap(), 4);
+    }
+
     #[test]
     fn test_cancel_order() {
         let mut server = Server::new();
@@ -900,13 +1353,339 @@ mod tests {
         let history: TradeHistory = histories[0].clone();
 
         assert_eq!(history.id, 28457);
-        assert!(approx_eq!(f64, history.price, 4.00000100, ulps = 2));
-        assert!(approx_eq!(f64, history.qty, 12.00000000, ulps = 2));
-        assert_eq!(history.commission, "10.10000000");
+        assert_number(history.price, "4.00000100");
+        assert_number(history.qty, "12.00000000");
+        assert_eq!(history.commission.to_string(), "10.10000000");
         assert_eq!(history.commission_asset, "BNB");
         assert_eq!(history.time, 1499865549590);
         assert!(history.is_buyer);
         assert!(!history.is_maker);
         assert!(history.is_best_match);
     }
+
+    #[test]
+    fn oco_order() {
+        let mut server = Server::new();
+        let mock_oco_order = server
+            .mock("POST", "/api/v3/orderList/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "aboveClientOrderId=above&abovePrice=3&aboveType=LIMIT_MAKER&belowClientOrderId=x-HNA2TXFJ\\w+&belowPrice=1&belowStopPrice=1&belowTimeInForce=GTC&belowType=STOP_LOSS_LIMIT&quantity=5&recvWindow=1234&side=SELL&symbol=LTCBTC&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = OcoOrderRequest {
+            symbol: "LTCBTC".into(),
+            side: OrderSide::Sell,
+            quantity: number("5"),
+            above: OrderListLeg {
+                price: Some(number("3")),
+                client_order_id: Some("above".into()),
+                ..OrderListLeg::new(OrderType::LimitMaker)
+            },
+            below: OrderListLeg {
+                price: Some(number("1")),
+                stop_price: Some(number("1")),
+                time_in_force: Some(TimeInForce::GTC),
+                ..OrderListLeg::new(OrderType::StopLossLimit)
+            },
+            list_client_order_id: None,
+        };
+        let order_list = account.oco_order(order).unwrap();
+
+        mock_oco_order.assert();
+
+        assert_eq!(order_list.order_list_id, 1);
+        assert_eq!(order_list.contingency_type, "OCO");
+        assert_eq!(order_list.list_order_status, "EXECUTING");
+        assert_eq!(order_list.orders.len(), 2);
+        assert_eq!(order_list.order_reports.len(), 2);
+
+        let stop_loss = &order_list.order_reports[0];
+        assert_eq!(stop_loss.order_id, 10);
+        assert_eq!(stop_loss.type_name, "STOP_LOSS_LIMIT");
+        assert_number(stop_loss.stop_price, "1.0");
+        assert_number(stop_loss.orig_qty, "5.0");
+
+        let limit_maker = &order_list.order_reports[1];
+        assert_eq!(limit_maker.type_name, "LIMIT_MAKER");
+        assert_number(limit_maker.price, "3.0");
+        assert_number(limit_maker.stop_price, "0.0");
+    }
+
+    #[test]
+    fn otoco_order() {
+        let mut server = Server::new();
+        let mock_otoco_order = server
+            .mock("POST", "/api/v3/orderList/otoco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::Regex("listClientOrderId=my_list".into()),
+                Matcher::Regex("pendingAbovePrice=3&pendingAboveType=LIMIT_MAKER".into()),
+                Matcher::Regex("pendingBelowTrailingDelta=100&pendingBelowType=STOP_LOSS".into()),
+                Matcher::Regex("pendingQuantity=5&pendingSide=SELL".into()),
+                Matcher::Regex("workingPrice=2&workingQuantity=5&workingSide=BUY&workingTimeInForce=GTC&workingType=LIMIT".into()),
+            ]))
+            .with_body_from_file("tests/mocks/account/oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order = OtocoOrderRequest {
+            symbol: "LTCBTC".into(),
+            working_side: OrderSide::Buy,
+            working_quantity: number("5"),
+            working: OrderListLeg {
+                price: Some(number("2")),
+                time_in_force: Some(TimeInForce::GTC),
+                ..OrderListLeg::new(OrderType::Limit)
+            },
+            pending_side: OrderSide::Sell,
+            pending_quantity: number("5"),
+            pending_above: OrderListLeg {
+                price: Some(number("3")),
+                ..OrderListLeg::new(OrderType::LimitMaker)
+            },
+            pending_below: Some(OrderListLeg {
+                trailing_delta: Some(100),
+                ..OrderListLeg::new(OrderType::StopLoss)
+            }),
+            list_client_order_id: Some("my_list".into()),
+        };
+        let _ = account.otoco_order(order).unwrap();
+
+        mock_otoco_order.assert();
+    }
+
+    #[test]
+    fn all_order_lists() {
+        let mut server = Server::new();
+        let mock_all_order_lists = server
+            .mock("GET", "/api/v3/allOrderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/all_order_lists.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_lists = account.all_order_lists().unwrap();
+
+        mock_all_order_lists.assert();
+
+        assert_eq!(order_lists.len(), 2);
+        assert_eq!(order_lists[1].contingency_type, "OTO");
+        assert_eq!(order_lists[1].orders[0].order_id, 2);
+        assert!(order_lists[1].order_reports.is_empty());
+    }
+
+    #[test]
+    fn cancel_order_list() {
+        let mut server = Server::new();
+        let mock_cancel_order_list = server
+            .mock("DELETE", "/api/v3/orderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderListId=0&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_order_list.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_list = account.cancel_order_list("LTCBTC", 0).unwrap();
+
+        mock_cancel_order_list.assert();
+
+        assert_eq!(order_list.list_status_type, "ALL_DONE");
+        assert_eq!(order_list.order_reports[0].status, "CANCELED");
+        assert_eq!(
+            order_list.order_reports[0].orig_client_order_id.as_deref(),
+            Some("pO9ufTiFGg3nw2fOdgeOXa")
+        );
+    }
+
+    #[test]
+    fn get_account_omit_zero_balances() {
+        let mut server = Server::new();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "omitZeroBalances=true&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let account = account.get_account_omit_zero_balances().unwrap();
+
+        mock_get_account.assert();
+
+        assert_eq!(account.balances.len(), 2);
+    }
+
+    #[test]
+    fn get_commission_rates() {
+        let mut server = Server::new();
+        let mock_get_commission_rates = server
+            .mock("GET", "/api/v3/account/commission")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&symbol=LTCBTC&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/commission.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let commission = account.get_commission_rates("LTCBTC").unwrap();
+
+        mock_get_commission_rates.assert();
+
+        assert_eq!(commission.symbol, "LTCBTC");
+        assert_number(commission.standard_commission.maker, "0.0000001");
+        assert_number(commission.special_commission.unwrap().seller, "0.04");
+        assert_number(commission.tax_commission.buyer, "0.00000118");
+        assert!(commission.discount.enabled_for_account);
+        assert!(commission.discount.enabled_for_symbol);
+        assert_eq!(commission.discount.discount_asset, "BNB");
+        assert_number(commission.discount.discount, "0.75");
+    }
+
+    #[test]
+    fn get_unfilled_order_count() {
+        let mut server = Server::new();
+        let mock_get_unfilled_order_count = server
+            .mock("GET", "/api/v3/rateLimit/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/unfilled_order_count.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let counts = account.get_unfilled_order_count().unwrap();
+
+        mock_get_unfilled_order_count.assert();
+
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[0].rate_limit_type, "ORDERS");
+        assert_eq!(counts[0].interval, "SECOND");
+        assert_eq!(counts[0].interval_num, 10);
+        assert_eq!(counts[0].limit, 10000);
+        assert_eq!(counts[0].count, 0);
+        assert_eq!(counts[1].interval, "DAY");
+    }
+
+    #[test]
+    fn prevented_matches() {
+        let mut server = Server::new();
+        let mock_prevented_matches = server
+            .mock("GET", "/api/v3/myPreventedMatches")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromPreventedMatchId=1&limit=10&orderId=5&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/prevented_matches.json")
+            .create();
+        let mock_prevented_match = server
+            .mock("GET", "/api/v3/myPreventedMatches")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "preventedMatchId=1&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/account/prevented_matches.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let matches = account.prevented_matches("LTCBTC", 5, 1, 10).unwrap();
+        mock_prevented_matches.assert();
+
+        assert_eq!(matches[0].symbol, "LTCBTC");
+        assert_eq!(matches[0].prevented_match_id, 1);
+        assert_eq!(matches[0].taker_order_id, 5);
+        assert_eq!(matches[0].maker_symbol, "LTCBTC");
+        assert_eq!(matches[0].maker_order_id, 3);
+        assert_eq!(matches[0].trade_group_id, 1);
+        assert_eq!(matches[0].self_trade_prevention_mode, "EXPIRE_MAKER");
+        assert_number(matches[0].price, "1.1");
+        assert_number(matches[0].maker_prevented_quantity, "1.3");
+        assert_eq!(matches[0].transact_time, 1669101687094);
+
+        let matches = account.prevented_match("LTCBTC", 1).unwrap();
+        mock_prevented_match.assert();
+        assert_eq!(matches.len(), 1);
+    }
+
+    #[test]
+    fn allocations() {
+        let mut server = Server::new();
+        let mock_allocations = server
+            .mock("GET", "/api/v3/myAllocations")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderId=1&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/allocations.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let allocations = account
+            .allocations("LTCBTC", Some(1), None, None, None, None)
+            .unwrap();
+
+        mock_allocations.assert();
+
+        assert_eq!(allocations[0].symbol, "LTCBTC");
+        assert_eq!(allocations[0].allocation_id, 0);
+        assert_eq!(allocations[0].allocation_type, "SOR");
+        assert_eq!(allocations[0].order_id, 1);
+        assert_eq!(allocations[0].order_list_id, -1);
+        assert_number(allocations[0].qty, "5.0");
+        assert_number(allocations[0].quote_qty, "5.0");
+        assert_eq!(allocations[0].commission_asset, "BTC");
+        assert_eq!(allocations[0].time, 1687506878118);
+        assert!(allocations[0].is_buyer);
+        assert!(!allocations[0].is_maker);
+        assert!(!allocations[0].is_allocator);
+    }
 }
@@ -0,0 +1,139 @@
+#![cfg(feature = "async")]
+
+mod common;
+
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::general::*;
+use binance::market::*;
+use binance::futures::market::AsyncFuturesMarket;
+use binance::model::*;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+
+    #[tokio::test]
+    async fn ping() {
+        let mut server = Server::new_async().await;
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: AsyncGeneral = Binance::new_with_config(None, None, &config);
+
+        let pong = general.ping().await.unwrap();
+        mock_ping.assert_async().await;
+
+        assert_eq!(pong, "pong");
+    }
+
+    #[tokio::test]
+    async fn get_depth() {
+        let mut server = Server::new_async().await;
+        let mock_get_depth = server
+            .mock("GET", "/api/v3/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_depth.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: AsyncMarket = Binance::new_with_config(None, None, &config);
+
+        let order_book = market.get_depth("LTCBTC").await.unwrap();
+        mock_get_depth.assert_async().await;
+
+        assert_eq!(order_book.last_update_id, 1027024);
+        assert_eq!(
+            order_book.bids[0],
+            Bids::new(number("4.00000000"), number("431.00000000"))
+        );
+    }
+
+    #[tokio::test]
+    async fn get_klines() {
+        let mut server = Server::new_async().await;
+        let mock_get_klines = server
+            .mock("GET", "/api/v3/klines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("interval=5m&limit=10&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_klines.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: AsyncMarket = Binance::new_with_config(None, None, &config);
+
+        let answer = market
+            .get_klines("LTCBTC", "5m", 10, None, None)
+            .await
+            .unwrap();
+        mock_get_klines.assert_async().await;
+
+        match answer {
+            KlineSummaries::AllKlineSummaries(klines) => {
+                assert!(!klines.is_empty());
+                assert_eq!(klines[0].open_time, 1499040000000);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn get_balance() {
+        let mut server = Server::new_async().await;
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: AsyncAccount = Binance::new_with_config(None, None, &config);
+        let balance = account.get_balance("BTC").await.unwrap();
+
+        mock_get_account.assert_async().await;
+
+        assert_eq!(balance.asset, "BTC");
+        assert_eq!(balance.free.to_string(), "4723846.89208129");
+        assert_eq!(balance.locked.to_string(), "0.00000000");
+    }
+
+    #[tokio::test]
+    async fn open_interest_statistics() {
+        let mut server = Server::new_async().await;
+        let mock_open_interest_statistics = server
+            .mock("GET", "/futures/data/openInterestHist")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=10&period=5m&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/open_interest_statistics.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: AsyncFuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let open_interest_hists = market
+            .open_interest_statistics("BTCUSDT", "5m", 10, None, None)
+            .await
+            .unwrap();
+        mock_open_interest_statistics.assert_async().await;
+
+        assert_eq!(open_interest_hists.len(), 2);
+        assert_eq!(open_interest_hists[0].symbol, "BTCUSDT");
+    }
+}
@@ -0,0 +1,123 @@
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::cassette::*;
+use binance::general::*;
+use binance::userstream::*;
+use binance::websockets::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use std::sync::atomic::AtomicBool;
+
+    fn cassette_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("binance-{}-{}.jsonl", name, std::process::id()))
+    }
+
+    #[test]
+    fn record_then_replay() {
+        let path = cassette_path("record_then_replay");
+        let mut server = Server::new();
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .expect(1)
+            .create();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+            .set_cassette(Cassette::record(&path).unwrap());
+        let general: General = Binance::new_with_config(None, None, &config);
+        let account: Account =
+            Binance::new_with_config(Some("api_key".into()), Some("secret".into()), &config);
+        general.ping().unwrap();
+        let recorded = account.get_account().unwrap();
+
+        // no server behind the replay, and another key signs the requests
+        let config = Config::default()
+            .set_rest_api_endpoint("http://127.0.0.1:1")
+            .set_recv_window(1234)
+            .set_cassette(Cassette::replay(&path).unwrap());
+        let general: General = Binance::new_with_config(None, None, &config);
+        let account: Account =
+            Binance::new_with_config(Some("other".into()), Some("other".into()), &config);
+        general.ping().unwrap();
+        let replayed = account.get_account().unwrap();
+
+        mock_ping.assert();
+        mock_get_account.assert();
+        assert_eq!(replayed.balances.len(), recorded.balances.len());
+        assert_eq!(replayed.maker_commission, recorded.maker_commission);
+
+        // each recorded response is served once
+        let err = general.ping().unwrap_err();
+        assert!(err.to_string().contains("/api/v3/ping"));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn replay_websocket() {
+        let cassette = Cassette::replay("tests/mocks/cassette/trades.jsonl").unwrap();
+        let config = Config::default().set_cassette(cassette.clone());
+
+        let general: General = Binance::new_with_config(None, None, &config);
+        general.ping().unwrap();
+
+        let mut trades = Vec::new();
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            if let WebsocketEvent::Trade(trade) = event {
+                trades.push(trade.price.to_string());
+            }
+            Ok(())
+        });
+        web_socket
+            .connect_with_config("btcusdt@trade", &config)
+            .unwrap();
+        web_socket.event_loop(&AtomicBool::new(true)).unwrap();
+        web_socket.disconnect().unwrap();
+        drop(web_socket);
+
+        assert_eq!(trades, vec!["16500.00", "16501.00"]);
+        assert_eq!(cassette.mode(), CassetteMode::Replay);
+    }
+
+    #[test]
+    fn replay_user_data_stream() {
+        let cassette = Cassette::replay("tests/mocks/cassette/user_data.jsonl").unwrap();
+        let config = Config::default().set_cassette(cassette);
+        // not the listen key of the recording
+        let listen_key = "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1";
+
+        let user_stream: UserStream =
+            Binance::new_with_config(Some("api_key".into()), None, &config);
+        user_stream.keep_alive(listen_key).unwrap();
+
+        let mut balances = Vec::new();
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            if let WebsocketEvent::BalanceUpdate(update) = event {
+                balances.push(update.balance[0].asset.clone());
+            }
+            Ok(())
+        });
+        web_socket.connect_with_config(listen_key, &config).unwrap();
+        web_socket.event_loop(&AtomicBool::new(true)).unwrap();
+        web_socket.disconnect().unwrap();
+        drop(web_socket);
+
+        assert_eq!(balances, vec!["BTC"]);
+    }
+}
@@ -0,0 +1,154 @@
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::futures::account::FuturesAccount;
+use binance::general::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn now() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64
+    }
+
+    #[test]
+    fn sync_clock() {
+        let mut server = Server::new();
+        let mock_server_time = server
+            .mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(format!("{{\"serverTime\":{}}}", now() + 10_000))
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let offset = general.sync_clock().unwrap();
+        mock_server_time.assert();
+
+        assert!((offset - 10_000).abs() < 1_000);
+    }
+
+    #[test]
+    fn signed_requests_use_server_time() {
+        let mut server = Server::new();
+        let mock_server_time = server
+            .mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .expect(1)
+            .create();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=14998\\d{8}&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .expect(2)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+            .set_clock_sync(Duration::from_secs(60));
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        account.get_account().unwrap();
+        account.get_account().unwrap();
+        mock_server_time.assert();
+        mock_get_account.assert();
+    }
+
+    #[test]
+    fn resync_on_timestamp_error() {
+        let mut server = Server::new();
+        let mock_server_time = server
+            .mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .expect(2)
+            .create();
+        let mock_timestamp_error = server
+            .mock("GET", "/api/v3/account")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(
+                "{\"code\":-1021,\"msg\":\"Timestamp for this request is outside of the recvWindow.\"}",
+            )
+            .expect(1)
+            .create();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_clock_sync(Duration::from_secs(60));
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        account.get_account().unwrap();
+        mock_server_time.assert();
+        mock_timestamp_error.assert();
+        mock_get_account.assert();
+    }
+
+    #[test]
+    fn offsets_are_kept_per_host() {
+        let mut spot_server = Server::new();
+        let mut futures_server = Server::new();
+        let mock_spot_time = spot_server
+            .mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/server_time.json")
+            .expect(1)
+            .create();
+        let mock_futures_time = futures_server
+            .mock("GET", "/fapi/v1/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{\"serverTime\":1600000000000}")
+            .expect(1)
+            .create();
+        let mock_get_account = spot_server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("timestamp=14998\\d{8}&".into()))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .expect(2)
+            .create();
+        let mock_futures_balance = futures_server
+            .mock("GET", "/fapi/v2/balance")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("timestamp=16000\\d{8}&".into()))
+            .with_body("[]")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(spot_server.url())
+            .set_futures_rest_api_endpoint(futures_server.url())
+            .set_clock_sync(Duration::from_secs(60));
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let futures_account: FuturesAccount = Binance::new_with_config(None, None, &config);
+
+        account.get_account().unwrap();
+        futures_account.account_balance().unwrap();
+        // syncing the futures clock leaves the spot offset alone
+        account.get_account().unwrap();
+        mock_spot_time.assert();
+        mock_futures_time.assert();
+        mock_get_account.assert();
+        mock_futures_balance.assert();
+    }
+}
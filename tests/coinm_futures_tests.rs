@@ -0,0 +1,283 @@
+mod common;
+
+use binance::api::*;
+use binance::config::*;
+use binance::account::OrderSide;
+use binance::futures::account::{OrderType, TimeInForce, WorkingType};
+use binance::futures::coinm::account::*;
+use binance::futures::coinm::general::*;
+use binance::futures::coinm::market::*;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+
+    #[test]
+    fn exchange_info() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/dapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/coinm/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_coinm_rest_api_endpoint(server.url());
+        let general: CoinMGeneral = Binance::new_with_config(None, None, &config);
+
+        let symbol = general.get_symbol_info("btcusd_200925").unwrap();
+        mock_exchange_info.assert();
+
+        assert_eq!(symbol.pair, "BTCUSD");
+        assert_eq!(symbol.contract_type, "CURRENT_QUARTER");
+        assert_eq!(symbol.delivery_date, 1601020800000);
+        assert_eq!(symbol.contract_size, 100);
+        assert_eq!(symbol.margin_asset, "BTC");
+        assert_eq!(symbol.order_types.len(), 5);
+        assert_number(symbol.trigger_protect.unwrap(), "0.05");
+
+        // 10 contracts of 100 USD at 10000 USD/BTC
+        assert_number(
+            symbol.base_qty(number("10.0"), number("10000")).unwrap(),
+            "0.1",
+        );
+        assert_eq!(
+            symbol.contracts(number("0.1049"), number("10000")),
+            Some(10)
+        );
+        assert_eq!(symbol.contracts(number("0"), number("10000")), Some(0));
+
+        // invalid inputs are told apart from 0 contracts
+        assert_eq!(symbol.base_qty(number("10"), number("0")), None);
+        assert_eq!(symbol.contracts(number("0.1"), number("0")), None);
+        assert_eq!(symbol.contracts(number("-0.1"), number("10000")), None);
+        assert_eq!(
+            symbol.contracts(number("100000000000000000000000"), number("100000")),
+            None
+        );
+    }
+
+    #[test]
+    fn get_depth() {
+        let mut server = Server::new();
+        let mock_depth = server
+            .mock("GET", "/dapi/v1/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^limit=5&symbol=BTCUSD_PERP$".into()))
+            .with_body_from_file("tests/mocks/futures/coinm/depth.json")
+            .create();
+
+        let config = Config::default().set_coinm_rest_api_endpoint(server.url());
+        let market: CoinMMarket = Binance::new_with_config(None, None, &config);
+
+        let order_book = market.get_depth("BTCUSD_PERP", 5).unwrap();
+        mock_depth.assert();
+
+        assert_eq!(order_book.pair, "BTCUSD");
+        assert_number(order_book.bids[0].qty, "431.0");
+        assert_number(order_book.asks[0].price, "9638.2");
+    }
+
+    #[test]
+    fn get_trades() {
+        let mut server = Server::new();
+        let mock_trades = server
+            .mock("GET", "/dapi/v1/trades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^symbol=BTCUSD_PERP$".into()))
+            .with_body_from_file("tests/mocks/futures/coinm/trades.json")
+            .create();
+
+        let config = Config::default().set_coinm_rest_api_endpoint(server.url());
+        let market: CoinMMarket = Binance::new_with_config(None, None, &config);
+
+        let trades = market.get_trades("BTCUSD_PERP", None).unwrap();
+        mock_trades.assert();
+
+        assert_eq!(trades.len(), 1);
+        assert_number(trades[0].qty, "1.0");
+        assert_number(trades[0].base_qty, "0.01037883");
+        assert!(trades[0].is_buyer_maker);
+    }
+
+    #[test]
+    fn get_mark_price() {
+        let mut server = Server::new();
+        let mock_mark_price = server
+            .mock("GET", "/dapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^symbol=BTCUSD_PERP$".into()))
+            .with_body_from_file("tests/mocks/futures/coinm/mark_price.json")
+            .create();
+
+        let config = Config::default().set_coinm_rest_api_endpoint(server.url());
+        let market: CoinMMarket = Binance::new_with_config(None, None, &config);
+
+        let mark_price = market.get_mark_price("BTCUSD_PERP").unwrap();
+        mock_mark_price.assert();
+
+        assert_eq!(mark_price.symbol, "BTCUSD_PERP");
+        assert_number(mark_price.mark_price, "11029.69574559");
+        assert_number(mark_price.last_funding_rate.unwrap(), "0.00071003");
+    }
+
+    #[test]
+    fn get_pair_mark_prices() {
+        let mut server = Server::new();
+        let mock_mark_prices = server
+            .mock("GET", "/dapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^pair=BTCUSD$".into()))
+            .with_body_from_file("tests/mocks/futures/coinm/pair_mark_prices.json")
+            .create();
+
+        let config = Config::default().set_coinm_rest_api_endpoint(server.url());
+        let market: CoinMMarket = Binance::new_with_config(None, None, &config);
+
+        let mark_prices = market.get_pair_mark_prices("BTCUSD").unwrap();
+        mock_mark_prices.assert();
+
+        assert_eq!(mark_prices.len(), 2);
+        // delivery contracts have no funding
+        assert_eq!(mark_prices[1].symbol, "BTCUSD_200925");
+        assert_eq!(mark_prices[1].last_funding_rate, None);
+        assert_eq!(mark_prices[1].next_funding_time, 0);
+    }
+
+    #[test]
+    fn limit_buy() {
+        let mut server = Server::new();
+        let mock_limit_buy = server
+            .mock("POST", "/dapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=9000&quantity=10&recvWindow=1234&side=BUY&symbol=BTCUSD_200925&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/coinm/order.json")
+            .create();
+
+        let config = Config::default()
+            .set_coinm_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: CoinMAccount = Binance::new_with_config(None, None, &config);
+
+        let order = account
+            .limit_buy("BTCUSD_200925", 10, number("9000"), TimeInForce::GTC)
+            .unwrap();
+        mock_limit_buy.assert();
+
+        assert_eq!(order.order_id, 22542179);
+        assert_eq!(order.pair, "BTCUSD");
+        assert_number(order.orig_qty, "10.0");
+        assert_number(order.cum_base, "0.0");
+        assert_eq!(order.status, "NEW");
+    }
+
+    #[test]
+    fn place_order() {
+        let mut server = Server::new();
+        let mock_place_order = server
+            .mock("POST", "/dapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "priceProtect=TRUE&quantity=10&recvWindow=1234&reduceOnly=true&side=SELL&stopPrice=8000&symbol=BTCUSD_200925&timestamp=\\d+&type=STOP_MARKET&workingType=MARK_PRICE&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/coinm/order.json")
+            .create();
+
+        let config = Config::default()
+            .set_coinm_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: CoinMAccount = Binance::new_with_config(None, None, &config);
+
+        let order = CoinMOrderRequest::new("BTCUSD_200925", OrderSide::Sell, OrderType::StopMarket)
+            .set_quantity(10)
+            .set_reduce_only(true)
+            .set_stop_price(number("8000"))
+            .set_working_type(WorkingType::MarkPrice)
+            .set_price_protect(true);
+        account.place_order(order).unwrap();
+        mock_place_order.assert();
+    }
+
+    #[test]
+    fn position_information() {
+        let mut server = Server::new();
+        let mock_position_risk = server
+            .mock("GET", "/dapi/v1/positionRisk")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^pair=BTCUSD&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/coinm/position_risk.json")
+            .create();
+
+        let config = Config::default()
+            .set_coinm_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: CoinMAccount = Binance::new_with_config(None, None, &config);
+
+        let positions = account.position_information(Some("BTCUSD")).unwrap();
+        mock_position_risk.assert();
+
+        assert_eq!(positions.len(), 1);
+        assert_number(positions[0].position_amount, "-5.0");
+        assert_number(positions[0].max_qty, "250.0");
+        assert_number(positions[0].notional_value, "-0.02731889");
+        assert!(!positions[0].is_auto_add_margin);
+    }
+
+    #[test]
+    fn account_information() {
+        let mut server = Server::new();
+        let mock_account = server
+            .mock("GET", "/dapi/v1/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/coinm/account.json")
+            .create();
+
+        let config = Config::default()
+            .set_coinm_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: CoinMAccount = Binance::new_with_config(None, None, &config);
+
+        let information = account.account_information().unwrap();
+        mock_account.assert();
+
+        assert_eq!(information.fee_tier, 2);
+        assert_eq!(information.assets[0].asset, "BTC");
+        assert_number(information.assets[0].wallet_balance, "0.00241969");
+        assert_eq!(information.positions[0].symbol, "BTCUSD_201225");
+        assert_number(information.positions[0].max_qty, "50.0");
+    }
+
+    #[test]
+    fn account_balance() {
+        let mut server = Server::new();
+        let mock_balance = server
+            .mock("GET", "/dapi/v1/balance")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/coinm/balance.json")
+            .create();
+
+        let config = Config::default()
+            .set_coinm_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: CoinMAccount = Binance::new_with_config(None, None, &config);
+
+        let balances = account.account_balance().unwrap();
+        mock_balance.assert();
+
+        assert_eq!(balances[0].asset, "BTC");
+        assert_number(balances[0].withdraw_available, "0.0025");
+    }
+}
@@ -0,0 +1,17 @@
+//! Helpers for the tests that run with and without the `decimal` feature.
+#![allow(dead_code)]
+
+use binance::model::Number;
+
+/// `value` as a `Number`, `f64` or `Decimal`.
+pub fn number(value: &str) -> Number {
+    value.parse().unwrap()
+}
+
+/// Asserts that `left` is `right`, to a relative 1e-12 so `f64` rounding passes.
+#[track_caller]
+pub fn assert_number(left: Number, right: &str) {
+    let right = number(right);
+    let equal = left == right || (left - right).abs() <= right.abs() * number("0.000000000001");
+    assert!(equal, "{} != {}", left, right);
+}
@@ -0,0 +1,154 @@
+use binance::api::*;
+use binance::config::*;
+use binance::general::*;
+use binance::websockets::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::atomic::AtomicBool;
+    use std::thread;
+    use std::time::Duration;
+    use tungstenite::Message;
+
+    const TRADE: &str = "{\"e\":\"trade\",\"E\":1672515782136,\"s\":\"BTCUSDT\",\"t\":12345,\"p\":\"16500.00\",\"q\":\"0.001\",\"b\":88,\"a\":50,\"T\":1672515782136,\"m\":true,\"M\":true}";
+
+    // serves one websocket frame on the tunnel opened through the proxy
+    fn serve_websocket(stream: TcpStream) {
+        let mut socket = tungstenite::accept(stream).unwrap();
+        socket.send(Message::Text(TRADE.into())).unwrap();
+        socket.close(None).unwrap();
+        while socket.read().is_ok() {}
+    }
+
+    fn read_trade(config: &Config) -> String {
+        let mut price = String::new();
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            if let WebsocketEvent::Trade(trade) = event {
+                price = trade.price.to_string();
+            }
+            Ok(())
+        });
+        web_socket
+            .connect_with_config("btcusdt@trade", config)
+            .unwrap();
+        // ends with the close frame sent by the server
+        assert!(web_socket.event_loop(&AtomicBool::new(true)).is_err());
+        drop(web_socket);
+        price
+    }
+
+    #[test]
+    fn websocket_through_http_proxy() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let proxy = format!("http://user:p%40ss@{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut byte = [0u8; 1];
+            while !request.ends_with(b"\r\n\r\n") {
+                stream.read_exact(&mut byte).unwrap();
+                request.push(byte[0]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                .unwrap();
+            serve_websocket(stream);
+            String::from_utf8(request).unwrap()
+        });
+
+        let config = Config::default()
+            .set_ws_endpoint("ws://stream.binance.invalid:9443/ws")
+            .set_proxy(proxy)
+            .set_connect_timeout(Duration::from_secs(5));
+        assert_eq!(read_trade(&config), "16500.00");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("CONNECT stream.binance.invalid:9443 HTTP/1.1\r\n"));
+        // base64 of `user:p@ss`
+        assert!(request.contains("Proxy-Authorization: Basic dXNlcjpwQHNz\r\n"));
+    }
+
+    #[test]
+    fn websocket_through_socks5_proxy() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let proxy = format!("socks5://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut greeting = [0u8; 3];
+            stream.read_exact(&mut greeting).unwrap();
+            assert_eq!(greeting, [5, 1, 0]);
+            stream.write_all(&[5, 0]).unwrap();
+
+            let mut header = [0u8; 5];
+            stream.read_exact(&mut header).unwrap();
+            assert_eq!(header[..4], [5, 1, 0, 3]);
+            let mut target = vec![0u8; header[4] as usize + 2];
+            stream.read_exact(&mut target).unwrap();
+            stream.write_all(&[5, 0, 0, 1, 127, 0, 0, 1, 0, 0]).unwrap();
+            serve_websocket(stream);
+            target
+        });
+
+        let config = Config::default()
+            .set_ws_endpoint("ws://stream.binance.invalid:9443/ws")
+            .set_proxy(proxy);
+        assert_eq!(read_trade(&config), "16500.00");
+
+        let target = server.join().unwrap();
+        assert_eq!(&target[..target.len() - 2], b"stream.binance.invalid");
+        assert_eq!(target[target.len() - 2..], 9443u16.to_be_bytes());
+    }
+
+    #[test]
+    fn rest_through_http_proxy() {
+        let mut proxy = Server::new();
+        let mock_ping = proxy
+            .mock("GET", "/api/v3/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint("http://api.binance.invalid")
+            .set_proxy(proxy.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        general.ping().unwrap();
+        mock_ping.assert();
+    }
+
+    #[test]
+    fn rest_invalid_proxy() {
+        let config = Config::default()
+            .set_rest_api_endpoint("http://api.binance.invalid")
+            .set_proxy("not a proxy");
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let err = general.ping().unwrap_err();
+        assert!(err.to_string().starts_with("Invalid connection settings"));
+    }
+
+    #[test]
+    fn rest_timeout() {
+        // accepts the connection but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let _server = thread::spawn(move || {
+            let (_stream, _) = listener.accept().unwrap();
+            thread::sleep(Duration::from_secs(5));
+        });
+
+        let config = Config::default()
+            .set_rest_api_endpoint(endpoint)
+            .set_timeout(Duration::from_millis(200));
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let err = general.ping().unwrap_err();
+        assert!(err.is_retryable());
+        assert!(err.binance_error().is_none());
+    }
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "decimal")]
+
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::market::*;
+use binance::model::*;
+use binance::validator::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+
+    fn number(value: &str) -> Number {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn get_depth() {
+        let mut server = Server::new();
+        let mock_get_depth = server
+            .mock("GET", "/api/v3/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_depth.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let order_book = market.get_depth("LTCBTC").unwrap();
+        mock_get_depth.assert();
+
+        assert_eq!(order_book.asks[0].price, number("4.000002"));
+        assert_eq!(order_book.asks[0].price.to_string(), "4.00000200");
+        assert_eq!(order_book.asks[0].qty, number("12"));
+    }
+
+    #[test]
+    fn get_account() {
+        let mut server = Server::new();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("timestamp=\\d+&signature=.*".into()))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let account = account.get_account().unwrap();
+        mock_get_account.assert();
+
+        assert_eq!(account.balances[0].free, number("4723846.89208129"));
+        assert_eq!(account.balances[0].locked, Number::ZERO);
+    }
+
+    #[test]
+    fn json_numbers_are_exact() {
+        // more digits than an f64 holds
+        let price: SymbolPrice =
+            serde_json::from_str(r#"{"symbol":"LTCBTC","price":0.12345678901234567891}"#).unwrap();
+        assert_eq!(price.price, number("0.12345678901234567891"));
+
+        let price: SymbolPrice =
+            serde_json::from_str(r#"{"symbol":"LTCBTC","price":1e-8}"#).unwrap();
+        assert_eq!(price.price, number("0.00000001"));
+
+        let balance: Balance =
+            serde_json::from_str(r#"{"asset":"BTC","free":0.12345678901234567891,"locked":"0"}"#)
+                .unwrap();
+        assert_eq!(balance.free, number("0.12345678901234567891"));
+    }
+
+    #[test]
+    fn rounding() {
+        let filters = vec![
+            Filters::PriceFilter {
+                min_price: "0.01000000".into(),
+                max_price: "1000000.00000000".into(),
+                tick_size: "0.01000000".into(),
+            },
+            Filters::LotSize {
+                min_qty: "0.00010000".into(),
+                max_qty: "9000.00000000".into(),
+                step_size: "0.00010000".into(),
+            },
+        ];
+        let validator = OrderValidator::new("BTCUSDT", &filters).unwrap();
+
+        assert_eq!(
+            validator.round_price(number("60000.125")),
+            number("60000.13")
+        );
+        assert_eq!(
+            validator.round_quantity(number("0.123456")),
+            number("0.1234")
+        );
+        assert!(validator.validate_price(number("0.3")).is_ok());
+        assert!(validator.validate_quantity(number("0.3"), false).is_ok());
+    }
+
+    #[test]
+    fn place_order() {
+        let mut server = Server::new();
+        let mock_place_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=0.1&quantity=0.3&recvWindow=\\d+&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        let order = SpotOrderRequest::new("LTCBTC", OrderSide::Buy, OrderType::Limit)
+            .set_quantity(number("0.3"))
+            .set_price(number("0.1"))
+            .set_time_in_force(TimeInForce::GTC)
+            .build()
+            .unwrap();
+        account.place_order(order).unwrap();
+        mock_place_order.assert();
+    }
+}
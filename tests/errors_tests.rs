@@ -0,0 +1,136 @@
+mod common;
+
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::errors::*;
+use binance::general::*;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+
+    #[test]
+    fn order_rejected() {
+        let mut server = Server::new();
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{\"code\":-2010,\"msg\":\"Account has insufficient balance for requested action.\"}")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        let err = account.limit_buy("LTCBTC", 1, number("0.1")).unwrap_err();
+        mock_limit_buy.assert();
+
+        let response = err.binance_error().unwrap();
+        assert_eq!(response.status, 400);
+        assert_eq!(response.code, -2010);
+        assert_eq!(response.error_code(), BinanceErrorCode::NewOrderRejected);
+        assert_eq!(response.endpoint, "/api/v3/order");
+        assert_eq!(
+            response.msg,
+            "Account has insufficient balance for requested action."
+        );
+        assert!(!err.is_retryable());
+        assert!(!err.is_rate_limited());
+    }
+
+    #[test]
+    fn too_many_requests() {
+        let mut server = Server::new();
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(429)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{\"code\":-1003,\"msg\":\"Too much request weight used; current limit is 6000 request weight per 1 MINUTE.\"}")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let err = general.ping().unwrap_err();
+        mock_ping.assert();
+
+        let response = err.binance_error().unwrap();
+        assert_eq!(response.status, 429);
+        assert_eq!(response.error_code(), BinanceErrorCode::TooManyRequests);
+        assert!(err.is_rate_limited());
+        assert!(err.is_retryable());
+    }
+
+    #[test]
+    fn service_unavailable_without_json() {
+        let mut server = Server::new();
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(503)
+            .with_header("content-type", "text/html")
+            .with_body("<html><body>503 Service Temporarily Unavailable</body></html>")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let err = general.ping().unwrap_err();
+        mock_ping.assert();
+
+        let response = err.binance_error().unwrap();
+        assert_eq!(response.status, 503);
+        assert_eq!(response.error_code(), BinanceErrorCode::Other(0));
+        assert_eq!(response.msg, "Service Unavailable");
+        assert_eq!(response.endpoint, "/api/v3/ping");
+        assert!(err.is_retryable());
+        assert!(!err.is_rate_limited());
+    }
+
+    #[test]
+    fn error_codes() {
+        assert_eq!(
+            BinanceErrorCode::from(-1021),
+            BinanceErrorCode::InvalidTimestamp
+        );
+        assert_eq!(
+            BinanceErrorCode::from(-2011),
+            BinanceErrorCode::CancelRejected
+        );
+        assert_eq!(BinanceErrorCode::from(-4164), BinanceErrorCode::MinNotional);
+        assert_eq!(
+            BinanceErrorCode::from(-9999),
+            BinanceErrorCode::Other(-9999)
+        );
+    }
+
+    #[test]
+    fn futures_error_codes() {
+        assert_eq!(
+            BinanceErrorCode::from(-2021),
+            BinanceErrorCode::CancelReplacePartiallyFailed
+        );
+        assert_eq!(
+            BinanceErrorCode::from_futures(-2021),
+            BinanceErrorCode::OrderWouldImmediatelyTrigger
+        );
+        assert_eq!(
+            BinanceErrorCode::from_futures(-1021),
+            BinanceErrorCode::InvalidTimestamp
+        );
+
+        let mut response = BinanceContentError {
+            code: -2022,
+            msg: "ReduceOnly Order is rejected.".into(),
+            status: 400,
+            endpoint: "/fapi/v1/order".into(),
+            data: None,
+        };
+        assert_eq!(response.error_code(), BinanceErrorCode::ReduceOnlyRejected);
+        response.endpoint = "/api/v3/order/cancelReplace".into();
+        assert_eq!(response.error_code(), BinanceErrorCode::CancelReplaceFailed);
+    }
+}
@@ -1,12 +1,14 @@
+mod common;
+
 use binance::api::*;
 use binance::config::*;
 use binance::futures::account::*;
+use common::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use mockito::{Server, Matcher};
-    use float_cmp::*;
     use binance::account::OrderSide;
     use binance::futures::model::Transaction;
 
@@ -33,12 +35,7 @@ mod tests {
 
         assert_eq!(response.leverage, 2);
         assert_eq!(response.symbol, "LTCUSDT");
-        assert!(approx_eq!(
-            f64,
-            response.max_notional_value,
-            9223372036854776000.0,
-            ulps = 2
-        ));
+        assert_number(response.max_notional_value, "9223372036854776000.0");
     }
 
     #[test]
@@ -158,7 +155,9 @@ mod tests {
             .set_recv_window(1234);
         let account: FuturesAccount = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        let transaction: Transaction = account.stop_market_close_buy("SRMUSDT", 10.5).unwrap();
+        let transaction: Transaction = account
+            .stop_market_close_buy("SRMUSDT", number("10.5"))
+            .unwrap();
 
         mock_stop_market_close_sell.assert();
 
@@ -166,7 +165,7 @@ mod tests {
         assert_eq!(transaction.side, "BUY");
         assert_eq!(transaction.orig_type, "STOP_MARKET");
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 10.5, ulps = 2));
+        assert_number(transaction.stop_price, "10.5");
     }
 
     #[test]
@@ -194,7 +193,9 @@ mod tests {
             .set_recv_window(1234);
         let account: FuturesAccount = Binance::new_with_config(None, None, &config);
         let _ = env_logger::try_init();
-        let transaction: Transaction = account.stop_market_close_sell("SRMUSDT", 7.4).unwrap();
+        let transaction: Transaction = account
+            .stop_market_close_sell("SRMUSDT", number("7.4"))
+            .unwrap();
 
         mock_stop_market_close_sell.assert();
 
@@ -202,7 +203,7 @@ mod tests {
         assert_eq!(transaction.side, "SELL");
         assert_eq!(transaction.orig_type, "STOP_MARKET");
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
+        assert_number(transaction.stop_price, "7.4");
     }
 
     #[test]
@@ -241,7 +242,7 @@ mod tests {
             qty: None,
             reduce_only: None,
             price: None,
-            stop_price: Some(7.4),
+            stop_price: Some(number("7.4")),
             close_position: Some(true),
             activation_price: None,
             callback_rate: None,
@@ -249,7 +250,6 @@ mod tests {
             price_protect: None,
             new_client_order_id: Some("myId".into()),
             good_till_date: None,
-            
         };
         let transaction: Transaction = account.custom_order(custom_order).unwrap();
 
@@ -259,7 +259,7 @@ mod tests {
         assert_eq!(transaction.side, "SELL");
         assert_eq!(transaction.orig_type, "STOP_MARKET");
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
+        assert_number(transaction.stop_price, "7.4");
     }
 
     #[test]
@@ -1,6 +1,12 @@
+mod common;
+
 use binance::api::*;
 use binance::config::*;
+use binance::futures::account::ContractType;
 use binance::futures::general::*;
+use binance::futures::registry::SymbolRegistry;
+use std::time::Duration;
+use common::*;
 
 #[cfg(test)]
 mod tests {
@@ -25,4 +31,96 @@ mod tests {
 
         assert_eq!(pong, "pong");
     }
+
+    #[test]
+    fn exchange_info() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/fapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/general/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let general: FuturesGeneral = Binance::new_with_config(None, None, &config);
+
+        let info = general.exchange_info().unwrap();
+        mock_exchange_info.assert();
+
+        assert_eq!(info.assets.len(), 3);
+        assert!(info.assets[0].margin_available);
+        assert_eq!(info.assets[2].asset, "BNB");
+        assert_eq!(info.assets[2].auto_asset_exchange, None);
+
+        let symbol = &info.symbols[1];
+        assert_eq!(symbol.symbol, "BTCUSDT_250627");
+        assert_eq!(symbol.pair, "BTCUSDT");
+        assert_eq!(symbol.contract_type, "CURRENT_QUARTER");
+        assert_eq!(symbol.delivery_date, 1751011200000);
+        assert_eq!(symbol.margin_asset, "USDT");
+        assert_eq!(symbol.underlying_type, "COIN");
+        assert_number(symbol.trigger_protect.unwrap(), "0.05");
+        assert_number(symbol.liquidation_fee.unwrap(), "0.0125");
+        assert_number(symbol.market_take_bound.unwrap(), "0.05");
+    }
+
+    #[test]
+    fn symbol_registry_lookups() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/fapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/general/exchange_info.json")
+            .expect(1)
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let general: FuturesGeneral = Binance::new_with_config(None, None, &config);
+        let registry = SymbolRegistry::new(general, Duration::from_secs(3600));
+
+        let symbol = registry.symbol("ethusdc").unwrap();
+        assert_eq!(symbol.margin_asset, "USDC");
+        assert!(registry.symbol("LTCUSDT").is_err());
+
+        let btc = registry.symbols_by_pair("BTCUSDT").unwrap();
+        assert_eq!(btc.len(), 2);
+
+        let perpetuals = registry
+            .symbols_by_contract_type(ContractType::Perpetual)
+            .unwrap();
+        let perpetuals: Vec<&str> = perpetuals.iter().map(|s| s.symbol.as_str()).collect();
+        assert_eq!(perpetuals, vec!["BTCUSDT", "ETHUSDC"]);
+
+        let usdt = registry.symbols_by_margin_asset("usdt").unwrap();
+        assert_eq!(usdt.len(), 2);
+
+        // everything came from the cache
+        mock_exchange_info.assert();
+    }
+
+    #[test]
+    fn symbol_registry_refresh() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/fapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/general/exchange_info.json")
+            .expect(4)
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let general: FuturesGeneral = Binance::new_with_config(None, None, &config);
+
+        // expired as soon as it is fetched
+        let registry = SymbolRegistry::new(general.clone(), Duration::ZERO);
+        registry.symbol("BTCUSDT").unwrap();
+        registry.symbol("BTCUSDT").unwrap();
+
+        let registry = SymbolRegistry::new(general, Duration::from_secs(3600));
+        registry.symbol("BTCUSDT").unwrap();
+        registry.invalidate();
+        registry.symbol("BTCUSDT").unwrap();
+
+        mock_exchange_info.assert();
+    }
 }
@@ -1,7 +1,12 @@
+mod common;
+
 use binance::api::*;
 use binance::config::*;
+use binance::futures::account::ContractType;
 use binance::futures::market::FuturesMarket;
 use binance::futures::model::OpenInterestHist;
+use binance::model::KlineSummaries;
+use common::*;
 
 #[cfg(test)]
 mod tests {
@@ -29,18 +34,271 @@ mod tests {
         let expectation = vec![
             OpenInterestHist {
                 symbol: "BTCUSDT".into(),
-                sum_open_interest: "20403.63700000".into(),
-                sum_open_interest_value: "150570784.07809979".into(),
+                sum_open_interest: "20403.63700000".parse().unwrap(),
+                sum_open_interest_value: "150570784.07809979".parse().unwrap(),
                 timestamp: 1583127900000,
             },
             OpenInterestHist {
                 symbol: "BTCUSDT".into(),
-                sum_open_interest: "20401.36700000".into(),
-                sum_open_interest_value: "149940752.14464448".into(),
+                sum_open_interest: "20401.36700000".parse().unwrap(),
+                sum_open_interest_value: "149940752.14464448".parse().unwrap(),
                 timestamp: 1583128200000,
             },
         ];
 
         assert_eq!(open_interest_hists, expectation);
     }
+
+    #[test]
+    fn get_mark_price() {
+        let mut server = Server::new();
+        let mock_mark_price = server
+            .mock("GET", "/fapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/futures/market/mark_price.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let mark_price = market.get_mark_price("BTCUSDT").unwrap();
+        mock_mark_price.assert();
+
+        assert_eq!(mark_price.symbol, "BTCUSDT");
+        assert_number(mark_price.mark_price, "11793.63104562");
+        assert_number(mark_price.index_price.unwrap(), "11781.8049597");
+        assert_number(mark_price.estimated_settle_price.unwrap(), "11781.16138815");
+        assert_number(mark_price.last_funding_rate, "0.00038246");
+        assert_number(mark_price.interest_rate.unwrap(), "0.0001");
+        assert_eq!(mark_price.next_funding_time, 1597392000000);
+        assert_eq!(mark_price.time, 1597370495002);
+    }
+
+    #[test]
+    fn get_funding_rate_history() {
+        let mut server = Server::new();
+        let mock_funding_rate_history = server
+            .mock("GET", "/fapi/v1/fundingRate")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^endTime=1570636800000&limit=2&startTime=1570608000000&symbol=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/funding_rate_history.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let funding_rates = market
+            .get_funding_rate_history("BTCUSDT", 1570608000000, 1570636800000, 2)
+            .unwrap();
+        mock_funding_rate_history.assert();
+
+        assert_eq!(funding_rates.len(), 2);
+        assert_eq!(funding_rates[0].symbol, "BTCUSDT");
+        assert_number(funding_rates[0].funding_rate, "-0.0375");
+        assert_eq!(funding_rates[0].funding_time, 1570608000000);
+        assert!(funding_rates[0].mark_price.is_none());
+        assert_number(funding_rates[1].mark_price.unwrap(), "34287.54619963");
+    }
+
+    #[test]
+    fn get_funding_info() {
+        let mut server = Server::new();
+        let mock_funding_info = server
+            .mock("GET", "/fapi/v1/fundingInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/market/funding_info.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let funding_info = market.get_funding_info().unwrap();
+        mock_funding_info.assert();
+
+        assert_eq!(funding_info[0].symbol, "BLZUSDT");
+        assert_number(funding_info[0].adjusted_funding_rate_cap, "0.025");
+        assert_number(funding_info[0].adjusted_funding_rate_floor, "-0.025");
+        assert_eq!(funding_info[0].funding_interval_hours, 8);
+        assert!(!funding_info[0].disclaimer);
+    }
+
+    #[test]
+    fn get_continuous_klines() {
+        let mut server = Server::new();
+        let mock_continuous_klines = server
+            .mock("GET", "/fapi/v1/continuousKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^contractType=PERPETUAL&interval=1m&limit=1&pair=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/continuous_klines.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let klines = market
+            .get_continuous_klines("BTCUSDT", ContractType::Perpetual, "1m", 1, None, None)
+            .unwrap();
+        mock_continuous_klines.assert();
+
+        let KlineSummaries::AllKlineSummaries(klines) = klines;
+        assert_eq!(klines[0].open_time, 1607444700000);
+        assert_eq!(klines[0].close.to_string(), "18896.13");
+        assert_eq!(klines[0].volume.to_string(), "492.363");
+        assert_eq!(klines[0].number_of_trades, 1874);
+    }
+
+    #[test]
+    fn get_price_klines() {
+        let mut server = Server::new();
+        let mock_index_price_klines = server
+            .mock("GET", "/fapi/v1/indexPriceKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^endTime=1591256459999&interval=1m&pair=BTCUSDT&startTime=1591256400000$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/index_price_klines.json")
+            .create();
+        let mock_mark_price_klines = server
+            .mock("GET", "/fapi/v1/markPriceKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^interval=1m&symbol=BTCUSDT$".into()))
+            .with_body_from_file("tests/mocks/futures/market/index_price_klines.json")
+            .create();
+        let mock_premium_index_klines = server
+            .mock("GET", "/fapi/v1/premiumIndexKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^interval=1h&limit=24&symbol=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/index_price_klines.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let KlineSummaries::AllKlineSummaries(klines) = market
+            .get_index_price_klines("BTCUSDT", "1m", None, 1591256400000, 1591256459999)
+            .unwrap();
+        mock_index_price_klines.assert();
+        assert_eq!(klines[0].open_time, 1591256400000);
+        assert_eq!(klines[0].open.to_string(), "9653.69440000");
+        assert_eq!(klines[0].high.to_string(), "9653.69640000");
+        assert_eq!(klines[0].low.to_string(), "9651.38600000");
+        assert_eq!(klines[0].close.to_string(), "9651.55200000");
+        assert_eq!(klines[0].close_time, 1591256459999);
+
+        market
+            .get_mark_price_klines("BTCUSDT", "1m", None, None, None)
+            .unwrap();
+        mock_mark_price_klines.assert();
+
+        market
+            .get_premium_index_klines("BTCUSDT", "1h", 24, None, None)
+            .unwrap();
+        mock_premium_index_klines.assert();
+    }
+
+    #[test]
+    fn long_short_ratios() {
+        let mut server = Server::new();
+        let mocks: Vec<_> = [
+            "topLongShortAccountRatio",
+            "topLongShortPositionRatio",
+            "globalLongShortAccountRatio",
+        ]
+        .iter()
+        .map(|endpoint| {
+            server
+                .mock("GET", format!("/futures/data/{}", endpoint).as_str())
+                .with_header("content-type", "application/json;charset=UTF-8")
+                .match_query(Matcher::Regex(
+                    "^limit=2&period=5m&startTime=1583139600000&symbol=BTCUSDT$".into(),
+                ))
+                .with_body_from_file("tests/mocks/futures/market/long_short_ratio.json")
+                .create()
+        })
+        .collect();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let ratios = market
+            .top_long_short_account_ratio("BTCUSDT", "5m", 2, 1583139600000, None)
+            .unwrap();
+        market
+            .top_long_short_position_ratio("BTCUSDT", "5m", 2, 1583139600000, None)
+            .unwrap();
+        market
+            .global_long_short_account_ratio("BTCUSDT", "5m", 2, 1583139600000, None)
+            .unwrap();
+        for mock in mocks {
+            mock.assert();
+        }
+
+        assert_eq!(ratios.len(), 2);
+        assert_eq!(ratios[0].symbol, "BTCUSDT");
+        assert_number(ratios[0].long_short_ratio, "1.8105");
+        assert_number(ratios[0].long_account, "0.6442");
+        assert_number(ratios[0].short_account, "0.3558");
+        assert_eq!(ratios[0].timestamp, 1583139600000);
+    }
+
+    #[test]
+    fn taker_buy_sell_volume() {
+        let mut server = Server::new();
+        let mock_taker_volume = server
+            .mock("GET", "/futures/data/takerlongshortRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^period=1h&symbol=BTCUSDT$".into()))
+            .with_body_from_file("tests/mocks/futures/market/taker_buy_sell_volume.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let volumes = market
+            .taker_buy_sell_volume("BTCUSDT", "1h", None, None, None)
+            .unwrap();
+        mock_taker_volume.assert();
+
+        assert_number(volumes[0].buy_sell_ratio, "1.5586");
+        assert_number(volumes[0].buy_vol, "387.33");
+        assert_number(volumes[0].sell_vol, "248.503");
+        assert_eq!(volumes[0].timestamp, 1585614900000);
+    }
+
+    #[test]
+    fn basis() {
+        let mut server = Server::new();
+        let mock_basis = server
+            .mock("GET", "/futures/data/basis")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^contractType=PERPETUAL&limit=30&pair=BTCUSDT&period=5m$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/basis.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let basis = market
+            .basis("BTCUSDT", ContractType::Perpetual, "5m", None, None, None)
+            .unwrap();
+        mock_basis.assert();
+
+        assert_eq!(basis[0].pair, "BTCUSDT");
+        assert_eq!(basis[0].contract_type, "PERPETUAL");
+        assert_number(basis[0].futures_price, "34414.1");
+        assert_number(basis[0].index_price, "34400.15945055");
+        assert_number(basis[0].basis, "13.94054945");
+        assert_number(basis[0].basis_rate, "0.0004");
+        assert!(basis[0].annualized_basis_rate.is_none());
+        assert_eq!(basis[0].timestamp, 1698742800000);
+    }
 }
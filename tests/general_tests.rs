@@ -61,6 +61,8 @@ mod tests {
         mock_exchange_info.assert();
 
         assert!(exchange_info.symbols.len() > 1);
+        assert_eq!(exchange_info.sors[0].base_asset, "BTC");
+        assert_eq!(exchange_info.sors[0].symbols, vec!["BTCUSDT", "BTCUSDC"]);
     }
 
     #[test]
@@ -1,13 +1,15 @@
+mod common;
+
 use binance::api::*;
 use binance::config::*;
 use binance::market::*;
 use binance::model::*;
+use common::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use mockito::{Server, Matcher};
-    use float_cmp::*;
 
     #[test]
     fn get_depth() {
@@ -26,7 +28,10 @@ mod tests {
         mock_get_depth.assert();
 
         assert_eq!(order_book.last_update_id, 1027024);
-        assert_eq!(order_book.bids[0], Bids::new(4.00000000, 431.00000000));
+        assert_eq!(
+            order_book.bids[0],
+            Bids::new(number("4.00000000"), number("431.00000000"))
+        );
     }
 
     #[test]
@@ -46,7 +51,10 @@ mod tests {
         mock_get_custom_depth.assert();
 
         assert_eq!(order_book.last_update_id, 1027024);
-        assert_eq!(order_book.bids[0], Bids::new(4.00000000, 431.00000000));
+        assert_eq!(
+            order_book.bids[0],
+            Bids::new(number("4.00000000"), number("431.00000000"))
+        );
     }
 
     #[test]
@@ -69,10 +77,10 @@ mod tests {
                 assert!(!symbols.is_empty());
                 let first_symbol = symbols[0].clone();
                 assert_eq!(first_symbol.symbol, "LTCBTC");
-                assert!(approx_eq!(f64, first_symbol.price, 4.00000200, ulps = 2));
+                assert_number(first_symbol.price, "4.00000200");
                 let second_symbol = symbols[1].clone();
                 assert_eq!(second_symbol.symbol, "ETHBTC");
-                assert!(approx_eq!(f64, second_symbol.price, 0.07946600, ulps = 2));
+                assert_number(second_symbol.price, "0.07946600");
             }
         }
     }
@@ -94,7 +102,7 @@ mod tests {
         mock_get_price.assert();
 
         assert_eq!(symbol.symbol, "LTCBTC");
-        assert!(approx_eq!(f64, symbol.price, 4.00000200, ulps = 2));
+        assert_number(symbol.price, "4.00000200");
     }
 
     #[test]
@@ -114,7 +122,7 @@ mod tests {
         mock_get_average_price.assert();
 
         assert_eq!(symbol.mins, 5);
-        assert!(approx_eq!(f64, symbol.price, 9.35751834, ulps = 2));
+        assert_number(symbol.price, "9.35751834");
     }
 
     #[test]
@@ -137,46 +145,16 @@ mod tests {
                 assert!(!tickers.is_empty());
                 let first_ticker = tickers[0].clone();
                 assert_eq!(first_ticker.symbol, "LTCBTC");
-                assert!(approx_eq!(
-                    f64,
-                    first_ticker.bid_price,
-                    4.00000000,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(
-                    f64,
-                    first_ticker.bid_qty,
-                    431.00000000,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(
-                    f64,
-                    first_ticker.ask_price,
-                    4.00000200,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(f64, first_ticker.ask_qty, 9.00000000, ulps = 2));
+                assert_number(first_ticker.bid_price, "4.00000000");
+                assert_number(first_ticker.bid_qty, "431.00000000");
+                assert_number(first_ticker.ask_price, "4.00000200");
+                assert_number(first_ticker.ask_qty, "9.00000000");
                 let second_ticker = tickers[1].clone();
                 assert_eq!(second_ticker.symbol, "ETHBTC");
-                assert!(approx_eq!(
-                    f64,
-                    second_ticker.bid_price,
-                    0.07946700,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(f64, second_ticker.bid_qty, 9.00000000, ulps = 2));
-                assert!(approx_eq!(
-                    f64,
-                    second_ticker.ask_price,
-                    100000.00000000,
-                    ulps = 2
-                ));
-                assert!(approx_eq!(
-                    f64,
-                    second_ticker.ask_qty,
-                    1000.00000000,
-                    ulps = 2
-                ));
+                assert_number(second_ticker.bid_price, "0.07946700");
+                assert_number(second_ticker.bid_qty, "9.00000000");
+                assert_number(second_ticker.ask_price, "100000.00000000");
+                assert_number(second_ticker.ask_qty, "1000.00000000");
             }
         }
     }
@@ -198,10 +176,10 @@ mod tests {
         mock_get_book_ticker.assert();
 
         assert_eq!(book_ticker.symbol, "LTCBTC");
-        assert!(approx_eq!(f64, book_ticker.bid_price, 4.00000000, ulps = 2));
-        assert!(approx_eq!(f64, book_ticker.bid_qty, 431.00000000, ulps = 2));
-        assert!(approx_eq!(f64, book_ticker.ask_price, 4.00000200, ulps = 2));
-        assert!(approx_eq!(f64, book_ticker.ask_qty, 9.00000000, ulps = 2));
+        assert_number(book_ticker.bid_price, "4.00000000");
+        assert_number(book_ticker.bid_qty, "431.00000000");
+        assert_number(book_ticker.ask_price, "4.00000200");
+        assert_number(book_ticker.ask_qty, "9.00000000");
     }
 
     #[test]
@@ -221,37 +199,17 @@ mod tests {
         mock_get_24h_price_stats.assert();
 
         assert_eq!(price_stats.symbol, "BNBBTC");
-        assert_eq!(price_stats.price_change, "-94.99999800");
+        assert_eq!(price_stats.price_change.to_string(), "-94.99999800");
         assert_eq!(price_stats.price_change_percent, "-95.960");
-        assert_eq!(price_stats.weighted_avg_price, "0.29628482");
-        assert!(approx_eq!(
-            f64,
-            price_stats.prev_close_price,
-            0.10002000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
-            price_stats.last_price,
-            4.00000200,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.bid_price, 4.00000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.ask_price, 4.00000200, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            price_stats.open_price,
-            99.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
-            price_stats.high_price,
-            100.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.low_price, 0.10000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.volume, 8913.30000000, ulps = 2));
+        assert_eq!(price_stats.weighted_avg_price.to_string(), "0.29628482");
+        assert_number(price_stats.prev_close_price, "0.10002000");
+        assert_number(price_stats.last_price, "4.00000200");
+        assert_number(price_stats.bid_price, "4.00000000");
+        assert_number(price_stats.ask_price, "4.00000200");
+        assert_number(price_stats.open_price, "99.00000000");
+        assert_number(price_stats.high_price, "100.00000000");
+        assert_number(price_stats.low_price, "0.10000000");
+        assert_number(price_stats.volume, "8913.30000000");
         assert_eq!(price_stats.open_time, 1499783499040);
         assert_eq!(price_stats.close_time, 1499869899040);
         assert_eq!(price_stats.first_id, 28385);
@@ -279,37 +237,17 @@ mod tests {
         let price_stats = prices_stats[0].clone();
 
         assert_eq!(price_stats.symbol, "BNBBTC");
-        assert_eq!(price_stats.price_change, "-94.99999800");
+        assert_eq!(price_stats.price_change.to_string(), "-94.99999800");
         assert_eq!(price_stats.price_change_percent, "-95.960");
-        assert_eq!(price_stats.weighted_avg_price, "0.29628482");
-        assert!(approx_eq!(
-            f64,
-            price_stats.prev_close_price,
-            0.10002000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
-            price_stats.last_price,
-            4.00000200,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.bid_price, 4.00000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.ask_price, 4.00000200, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            price_stats.open_price,
-            99.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(
-            f64,
-            price_stats.high_price,
-            100.00000000,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, price_stats.low_price, 0.10000000, ulps = 2));
-        assert!(approx_eq!(f64, price_stats.volume, 8913.30000000, ulps = 2));
+        assert_eq!(price_stats.weighted_avg_price.to_string(), "0.29628482");
+        assert_number(price_stats.prev_close_price, "0.10002000");
+        assert_number(price_stats.last_price, "4.00000200");
+        assert_number(price_stats.bid_price, "4.00000000");
+        assert_number(price_stats.ask_price, "4.00000200");
+        assert_number(price_stats.open_price, "99.00000000");
+        assert_number(price_stats.high_price, "100.00000000");
+        assert_number(price_stats.low_price, "0.10000000");
+        assert_number(price_stats.volume, "8913.30000000");
         assert_eq!(price_stats.open_time, 1499783499040);
         assert_eq!(price_stats.close_time, 1499869899040);
         assert_eq!(price_stats.first_id, 28385);
@@ -339,16 +277,231 @@ mod tests {
                 let kline: KlineSummary = klines[0].clone();
 
                 assert_eq!(kline.open_time, 1499040000000);
-                assert_eq!(kline.open, "0.01634790");
-                assert_eq!(kline.high, "0.80000000");
-                assert_eq!(kline.low, "0.01575800");
-                assert_eq!(kline.close, "0.01577100");
-                assert_eq!(kline.volume, "148976.11427815");
+                assert_eq!(kline.open.to_string(), "0.01634790");
+                assert_eq!(kline.high.to_string(), "0.80000000");
+                assert_eq!(kline.low.to_string(), "0.01575800");
+                assert_eq!(kline.close.to_string(), "0.01577100");
+                assert_eq!(kline.volume.to_string(), "148976.11427815");
                 assert_eq!(kline.close_time, 1499644799999);
-                assert_eq!(kline.quote_asset_volume, "2434.19055334");
+                assert_eq!(kline.quote_asset_volume.to_string(), "2434.19055334");
                 assert_eq!(kline.number_of_trades, 308);
-                assert_eq!(kline.taker_buy_base_asset_volume, "1756.87402397");
-                assert_eq!(kline.taker_buy_quote_asset_volume, "28.46694368");
+                assert_eq!(
+                    kline.taker_buy_base_asset_volume.to_string(),
+                    "1756.87402397"
+                );
+                assert_eq!(
+                    kline.taker_buy_quote_asset_volume.to_string(),
+                    "28.46694368"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn get_trades() {
+        let mut server = Server::new();
+        let mock_get_trades = server
+            .mock("GET", "/api/v3/trades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=1&symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let trades = market.get_trades("LTCBTC", 1).unwrap();
+        mock_get_trades.assert();
+
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].id, 28457);
+        assert_number(trades[0].price, "4.000001");
+        assert_number(trades[0].qty, "12.0");
+        assert_number(trades[0].quote_qty, "48.000012");
+        assert_eq!(trades[0].time, 1499865549590);
+        assert!(trades[0].is_buyer_maker);
+        assert!(trades[0].is_best_match);
+    }
+
+    #[test]
+    fn get_historical_trades() {
+        let mut server = Server::new();
+        let mock_get_historical_trades = server
+            .mock("GET", "/api/v3/historicalTrades")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_header("x-mbx-apikey", "api_key")
+            .match_query(Matcher::Regex(
+                "^fromId=28457&limit=10&symbol=LTCBTC$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_trades.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let trades = market.get_historical_trades("LTCBTC", 28457, 10).unwrap();
+        mock_get_historical_trades.assert();
+
+        assert_eq!(trades[0].id, 28457);
+    }
+
+    #[test]
+    fn get_prices() {
+        let mut server = Server::new();
+        let mock_get_prices = server
+            .mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^symbols=%5B%22LTCBTC%22%2C%22ETHBTC%22%5D$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_all_prices.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let prices = market.get_prices(&["LTCBTC", "ETHBTC"]).unwrap();
+        mock_get_prices.assert();
+
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[1].symbol, "ETHBTC");
+        assert_number(prices[1].price, "0.07946600");
+    }
+
+    #[test]
+    fn get_book_tickers() {
+        let mut server = Server::new();
+        let mock_get_book_tickers = server
+            .mock("GET", "/api/v3/ticker/bookTicker")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^symbols=%5B%22LTCBTC%22%2C%22ETHBTC%22%5D$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_all_book_tickers.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let watchlist = vec!["LTCBTC".to_string(), "ETHBTC".to_string()];
+        let tickers = market.get_book_tickers(&watchlist).unwrap();
+        mock_get_book_tickers.assert();
+
+        assert_eq!(tickers.len(), 2);
+        assert_eq!(tickers[0].symbol, "LTCBTC");
+        assert_number(tickers[0].bid_qty, "431.0");
+    }
+
+    #[test]
+    fn get_24h_ticker_stats() {
+        let mut server = Server::new();
+        let mock_get_24h_ticker_stats = server
+            .mock("GET", "/api/v3/ticker/24hr")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^symbols=%5B%22BNBBTC%22%2C%22LTCBTC%22%5D&type=MINI$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_mini_ticker_stats.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let stats = market
+            .get_24h_ticker_stats(&["BNBBTC", "LTCBTC"], TickerType::Mini)
+            .unwrap();
+        mock_get_24h_ticker_stats.assert();
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].symbol, "BNBBTC");
+        assert!(stats[0].price_change.is_none());
+        assert!(stats[0].bid_price.is_none());
+        assert_number(stats[0].open_price, "99.0");
+        assert_number(stats[0].last_price, "4.000002");
+        assert_number(stats[0].quote_volume, "15.3");
+        assert_eq!(stats[1].symbol, "LTCBTC");
+        assert_eq!(stats[1].count, 10);
+    }
+
+    #[test]
+    fn get_rolling_window_stats() {
+        let mut server = Server::new();
+        let mock_get_rolling_window_stats = server
+            .mock("GET", "/api/v3/ticker")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^symbols=%5B%22BNBBTC%22%5D&windowSize=2d$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_rolling_window_stats.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let stats = market
+            .get_rolling_window_stats(&["BNBBTC"], Some("2d"), None)
+            .unwrap();
+        mock_get_rolling_window_stats.assert();
+
+        assert_eq!(stats[0].symbol, "BNBBTC");
+        assert_number(stats[0].price_change.unwrap(), "-8.0");
+        assert_eq!(stats[0].price_change_percent.as_deref(), Some("-88.889"));
+        assert_number(stats[0].weighted_avg_price.unwrap(), "2.60427807");
+        assert_eq!(stats[0].open_time, 1641859200000);
+        assert_eq!(stats[0].close_time, 1642031999999);
+        assert_eq!(stats[0].first_id, 0);
+        assert_eq!(stats[0].last_id, 60);
+        assert_eq!(stats[0].count, 61);
+    }
+
+    #[test]
+    fn get_trading_day_stats() {
+        let mut server = Server::new();
+        let mock_get_trading_day_stats = server
+            .mock("GET", "/api/v3/ticker/tradingDay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^symbols=%5B%22BNBBTC%22%2C%22LTCBTC%22%5D&timeZone=-1:00&type=MINI$".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_mini_ticker_stats.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let stats = market
+            .get_trading_day_stats(&["BNBBTC", "LTCBTC"], Some("-1:00"), TickerType::Mini)
+            .unwrap();
+        mock_get_trading_day_stats.assert();
+
+        assert_eq!(stats.len(), 2);
+        assert_number(stats[1].high_price, "0.072");
+    }
+
+    #[test]
+    fn get_ui_klines() {
+        let mut server = Server::new();
+        let mock_get_ui_klines = server
+            .mock("GET", "/api/v3/uiKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "interval=1d&limit=10&symbol=LTCBTC&timeZone=8".into(),
+            ))
+            .with_body_from_file("tests/mocks/market/get_klines.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let klines = market
+            .get_ui_klines("LTCBTC", "1d", 10, None, None, Some("8"))
+            .unwrap();
+        mock_get_ui_klines.assert();
+
+        match klines {
+            binance::model::KlineSummaries::AllKlineSummaries(klines) => {
+                assert_eq!(klines[0].open_time, 1499040000000);
+                assert_eq!(klines[0].close.to_string(), "0.01577100");
             }
         }
     }
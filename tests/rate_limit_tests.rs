@@ -0,0 +1,155 @@
+use binance::api::*;
+use binance::config::*;
+use binance::errors::ErrorKind;
+use binance::futures::coinm::general::CoinMGeneral;
+use binance::futures::coinm::market::CoinMMarket;
+use binance::futures::general::FuturesGeneral;
+use binance::futures::market::FuturesMarket;
+use binance::general::*;
+use binance::market::*;
+use binance::rate_limit::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use std::time::Duration;
+
+    #[test]
+    fn tracks_used_weight() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight", "42")
+            .with_header("x-mbx-used-weight-1m", "42")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        general.exchange_info().unwrap();
+        mock_exchange_info.assert();
+
+        let usage = general.client.rate_limit_usage();
+        let weight = usage
+            .iter()
+            .find(|u| u.kind == RateLimitKind::RequestWeight)
+            .unwrap();
+        assert_eq!(weight.interval, Duration::from_secs(60));
+        assert_eq!(weight.used, 42);
+        assert_eq!(weight.limit, Some(1200));
+
+        let orders: Vec<&RateLimitUsage> = usage
+            .iter()
+            .filter(|u| u.kind == RateLimitKind::Orders)
+            .collect();
+        assert_eq!(orders.len(), 2);
+        assert!(orders.iter().all(|u| u.used == 0));
+    }
+
+    #[test]
+    fn fail_fast_when_limit_reached() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight-1m", "1200")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create();
+        let mock_get_price = server
+            .mock("GET", "/api/v3/ticker/price")
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limit_policy(RateLimitPolicy::FailFast);
+        let general: General = Binance::new_with_config(None, None, &config);
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        general.exchange_info().unwrap();
+        mock_exchange_info.assert();
+
+        let err = market.get_price("LTCBTC").unwrap_err();
+        mock_get_price.assert();
+
+        match err.0 {
+            ErrorKind::RateLimitExceeded(usage, retry_after) => {
+                assert_eq!(usage.kind, RateLimitKind::RequestWeight);
+                assert_eq!(usage.used, 1200);
+                assert!(retry_after <= Duration::from_secs(60));
+            }
+            _ => panic!("unexpected error: {}", err),
+        }
+    }
+
+    #[test]
+    fn limits_are_kept_per_api() {
+        // USD-M and COIN-M futures share a host on the testnet
+        let mut server = Server::new();
+        let mock_futures_exchange_info = server
+            .mock("GET", "/fapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight-1m", "2400")
+            .with_body_from_file("tests/mocks/futures/general/exchange_info.json")
+            .create();
+        let mock_coinm_exchange_info = server
+            .mock("GET", "/dapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight-1m", "10")
+            .with_body_from_file("tests/mocks/futures/coinm/exchange_info.json")
+            .create();
+        let mock_futures_price = server
+            .mock("GET", "/fapi/v1/ticker/price")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+        let mock_coinm_depth = server
+            .mock("GET", "/dapi/v1/depth")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/coinm/depth.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_coinm_rest_api_endpoint(server.url())
+            .set_rate_limit_policy(RateLimitPolicy::FailFast);
+        let futures_general: FuturesGeneral = Binance::new_with_config(None, None, &config);
+        let futures_market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let coinm_general: CoinMGeneral = Binance::new_with_config(None, None, &config);
+        let coinm_market: CoinMMarket = Binance::new_with_config(None, None, &config);
+
+        futures_general.exchange_info().unwrap();
+        coinm_general.exchange_info().unwrap();
+        mock_futures_exchange_info.assert();
+        mock_coinm_exchange_info.assert();
+
+        // the USD-M weight does not count against COIN-M
+        coinm_market.get_depth("BTCUSD_PERP", 5).unwrap();
+        mock_coinm_depth.assert();
+
+        let err = futures_market.get_price("BTCUSDT").unwrap_err();
+        mock_futures_price.assert();
+        match err.0 {
+            ErrorKind::RateLimitExceeded(usage, _) => {
+                assert_eq!(usage.api, "/fapi");
+                assert_eq!(usage.used, 2400);
+                assert_eq!(usage.limit, Some(2400));
+            }
+            _ => panic!("unexpected error: {}", err),
+        }
+
+        let usage = coinm_market.client.rate_limit_usage();
+        let weight = |api: &str| {
+            usage
+                .iter()
+                .find(|u| u.api == api && u.kind == RateLimitKind::RequestWeight)
+                .map(|u| u.used)
+        };
+        assert_eq!(weight("/fapi"), Some(2400));
+        assert_eq!(weight("/dapi"), Some(10));
+    }
+}
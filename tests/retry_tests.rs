@@ -0,0 +1,158 @@
+mod common;
+
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::general::*;
+use binance::retry::*;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use std::time::Duration;
+
+    fn retry_policy() -> RetryPolicy {
+        RetryPolicy::new(3).set_backoff(Duration::from_millis(1), Duration::from_millis(10))
+    }
+
+    #[test]
+    fn retries_on_server_error() {
+        let mut server = Server::new();
+        let mock_unavailable = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(503)
+            .expect(2)
+            .create();
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_retry_policy(retry_policy());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let pong = general.ping().unwrap();
+        mock_unavailable.assert();
+        mock_ping.assert();
+
+        assert_eq!(pong, "pong");
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let mut server = Server::new();
+        let mock_unavailable = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(503)
+            .expect(3)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_retry_policy(retry_policy());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        assert!(general.ping().is_err());
+        mock_unavailable.assert();
+    }
+
+    #[test]
+    fn does_not_wait_longer_than_max_backoff() {
+        let mut server = Server::new();
+        let mock_too_many_requests = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(429)
+            .with_header("retry-after", "60")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_retry_policy(retry_policy());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        assert!(general.ping().is_err());
+        mock_too_many_requests.assert();
+    }
+
+    #[test]
+    fn never_retries_order_on_server_error() {
+        let mut server = Server::new();
+        let mock_unavailable = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .with_status(503)
+            .expect(1)
+            .create();
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_retry_policy(retry_policy());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        assert!(account.limit_buy("LTCBTC", 1, number("0.1")).is_err());
+        mock_unavailable.assert();
+        mock_limit_buy.assert();
+    }
+
+    #[test]
+    fn retries_rejected_order() {
+        let mut server = Server::new();
+        let mock_too_many_requests = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create();
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_retry_policy(retry_policy());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        let transaction = account.limit_buy("LTCBTC", 1, number("0.1")).unwrap();
+        mock_too_many_requests.assert();
+        mock_limit_buy.assert();
+
+        assert_eq!(transaction.order_id, 1);
+    }
+
+    #[test]
+    fn never_retries_order_when_disabled() {
+        let mut server = Server::new();
+        let mock_too_many_requests = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_retry_policy(retry_policy().set_non_idempotent(NonIdempotentRetry::Never));
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        assert!(account.limit_buy("LTCBTC", 1, number("0.1")).is_err());
+        mock_too_many_requests.assert();
+    }
+}
@@ -0,0 +1,105 @@
+use binance::signer::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(any(feature = "rsa", feature = "ed25519"))]
+    use base64::Engine;
+    #[cfg(any(feature = "rsa", feature = "ed25519"))]
+    use base64::engine::general_purpose::STANDARD as BASE64;
+
+    const PAYLOAD: &str = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+
+    #[test]
+    fn hmac_signer() {
+        // example from the Binance API documentation
+        let signer =
+            HmacSigner::new("NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j");
+
+        assert_eq!(
+            signer.sign(PAYLOAD),
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn ed25519_signer() {
+        use ed25519_dalek::Verifier;
+        use ed25519_dalek::pkcs8::DecodePrivateKey;
+
+        let pem = std::fs::read_to_string("tests/mocks/keys/ed25519.pem").unwrap();
+        let signer = Ed25519Signer::from_pem(&pem).unwrap();
+        let signature = BASE64.decode(signer.sign(PAYLOAD)).unwrap();
+
+        let key = ed25519_dalek::SigningKey::from_pkcs8_pem(&pem).unwrap();
+        let signature = ed25519_dalek::Signature::from_slice(&signature).unwrap();
+        assert!(
+            key.verifying_key()
+                .verify(PAYLOAD.as_bytes(), &signature)
+                .is_ok()
+        );
+    }
+
+    #[cfg(feature = "rsa")]
+    #[test]
+    fn rsa_signer() {
+        use rsa::pkcs1::DecodeRsaPrivateKey;
+        use rsa::pkcs1v15::{Signature, VerifyingKey};
+        use rsa::signature::Verifier;
+        use sha2::Sha256;
+
+        let pkcs1 = RsaSigner::from_pem_file("tests/mocks/keys/rsa_pkcs1.pem").unwrap();
+        let pkcs8 = RsaSigner::from_pem_file("tests/mocks/keys/rsa_pkcs8.pem").unwrap();
+        // PKCS#1 v1.5 signatures are deterministic
+        assert_eq!(pkcs1.sign(PAYLOAD), pkcs8.sign(PAYLOAD));
+
+        let pem = std::fs::read_to_string("tests/mocks/keys/rsa_pkcs1.pem").unwrap();
+        let key = rsa::RsaPrivateKey::from_pkcs1_pem(&pem).unwrap();
+        let verifying_key = VerifyingKey::<Sha256>::new(key.to_public_key());
+        let signature = BASE64.decode(pkcs1.sign(PAYLOAD)).unwrap();
+        let signature = Signature::try_from(signature.as_slice()).unwrap();
+        assert!(verifying_key.verify(PAYLOAD.as_bytes(), &signature).is_ok());
+    }
+
+    #[cfg(any(feature = "rsa", feature = "ed25519"))]
+    #[test]
+    fn invalid_private_key() {
+        #[cfg(feature = "ed25519")]
+        assert!(Ed25519Signer::from_pem("not a key").is_err());
+        #[cfg(feature = "rsa")]
+        assert!(RsaSigner::from_pem("not a key").is_err());
+        #[cfg(feature = "ed25519")]
+        assert!(Ed25519Signer::from_pem_file("tests/mocks/keys/missing.pem").is_err());
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn signed_request_with_ed25519() {
+        use binance::account::Account;
+        use binance::api::Binance;
+        use binance::config::Config;
+        use mockito::{Matcher, Server};
+
+        let mut server = Server::new();
+        // base64 signature of 64 bytes, with `+`, `/` and `=` percent-encoded
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=([A-Za-z0-9]|%2B|%2F){86}%3D%3D$".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let signer = Ed25519Signer::from_pem_file("tests/mocks/keys/ed25519.pem").unwrap();
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+            .set_signer(signer);
+        let account: Account = Binance::new_with_config(Some("api_key".into()), None, &config);
+
+        let _ = account.get_account().unwrap();
+        mock_get_account.assert();
+    }
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::cassette::*;
+use binance::websockets::*;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use std::io::Write;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Output(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Output {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    // runs `f` with every event, down to TRACE, written to the returned string
+    fn capture<F: FnOnce()>(f: F) -> String {
+        let output = Output::default();
+        let writer = output.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::TRACE)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        tracing::subscriber::with_default(subscriber, f);
+        let bytes = output.0.lock().unwrap().clone();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn signed_request_is_redacted() {
+        let mut server = Server::new();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight-1m", "20")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let mut account: Account = Binance::new_with_config(
+            Some("my_api_key".into()),
+            Some("my_secret_key".into()),
+            &config,
+        );
+        account.set_verbose(true);
+
+        let output = capture(|| {
+            account.get_account().unwrap();
+        });
+        mock_get_account.assert();
+
+        assert!(output.contains("endpoint=\"/api/v3/account\""));
+        assert!(output.contains("status=200"));
+        assert!(output.contains("used_weight=20"));
+        assert!(output.contains("latency_ms="));
+        assert!(output.contains("signature=<redacted>"));
+        assert!(output.contains("\"x-mbx-apikey\": \"<redacted>\""));
+        assert!(!output.contains("my_api_key"));
+        assert!(!output.contains("my_secret_key"));
+    }
+
+    #[test]
+    fn rejected_request() {
+        let mut server = Server::new();
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{\"code\":-2010,\"msg\":\"Account has insufficient balance for requested action.\"}")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        let output = capture(|| {
+            account.limit_buy("LTCBTC", 1, number("0.1")).unwrap_err();
+        });
+        mock_limit_buy.assert();
+
+        assert!(output.contains("WARN"));
+        assert!(output.contains("request rejected"));
+        assert!(output.contains("status=400"));
+        assert!(output.contains("error_code=-2010"));
+    }
+
+    #[test]
+    fn websocket_parse_failure() {
+        let path =
+            std::env::temp_dir().join(format!("binance-tracing-{}.jsonl", std::process::id()));
+        let listen_key = "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1";
+        std::fs::write(
+            &path,
+            "{\"type\":\"ws\",\"stream\":\"/ws/<listenKey>\",\"frame\":\"not json\"}\n",
+        )
+        .unwrap();
+        let config = Config::default().set_cassette(Cassette::replay(&path).unwrap());
+
+        let output = capture(|| {
+            let mut web_socket = WebSockets::new(|_event: WebsocketEvent| Ok(()));
+            web_socket.connect_with_config(listen_key, &config).unwrap();
+            assert!(web_socket.event_loop(&AtomicBool::new(true)).is_err());
+        });
+        std::fs::remove_file(path).unwrap();
+
+        assert!(output.contains("invalid websocket message"));
+        assert!(output.contains("stream=/ws/<listenKey>"));
+        assert!(!output.contains(listen_key));
+    }
+}
@@ -0,0 +1,138 @@
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::errors::*;
+use binance::general::*;
+use binance::transport::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+    use reqwest::header::HeaderMap;
+    use std::sync::{Arc, Mutex};
+
+    /// Serves canned responses and keeps the requests it was given.
+    #[derive(Debug, Clone, Default)]
+    struct InMemoryTransport {
+        responses: Arc<Mutex<Vec<(u16, String)>>>,
+        requests: Arc<Mutex<Vec<HttpRequest>>>,
+    }
+
+    impl InMemoryTransport {
+        fn respond(&self, status: u16, body: &str) {
+            self.responses.lock().unwrap().push((status, body.into()));
+        }
+    }
+
+    impl Transport for InMemoryTransport {
+        fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+            self.requests.lock().unwrap().push(request);
+            let (status, body) = self.responses.lock().unwrap().remove(0);
+            Ok(HttpResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                headers: HeaderMap::new(),
+                body: body.into_bytes(),
+            })
+        }
+    }
+
+    #[test]
+    fn public_request() {
+        let transport = InMemoryTransport::default();
+        transport.respond(200, "{}");
+
+        let config = Config::default()
+            .set_rest_api_endpoint("https://example.com")
+            .set_transport(transport.clone());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        general.ping().unwrap();
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, reqwest::Method::GET);
+        assert_eq!(requests[0].url, "https://example.com/api/v3/ping");
+        assert!(requests[0].headers.is_empty());
+        assert!(requests[0].body.is_none());
+    }
+
+    #[test]
+    fn signed_request() {
+        let transport = InMemoryTransport::default();
+        transport.respond(
+            200,
+            &std::fs::read_to_string("tests/mocks/account/get_account.json").unwrap(),
+        );
+
+        let config = Config::default()
+            .set_rest_api_endpoint("https://example.com")
+            .set_recv_window(1234)
+            .set_transport(transport.clone());
+        let account: Account =
+            Binance::new_with_config(Some("api_key".into()), Some("secret".into()), &config);
+
+        let _ = account.get_account().unwrap();
+
+        let requests = transport.requests.lock().unwrap();
+        assert!(
+            requests[0]
+                .url
+                .starts_with("https://example.com/api/v3/account?recvWindow=1234&timestamp=")
+        );
+        assert!(requests[0].url.contains("&signature="));
+        assert_eq!(requests[0].headers["X-MBX-APIKEY"], "api_key");
+    }
+
+    #[test]
+    fn error_response() {
+        let transport = InMemoryTransport::default();
+        transport.respond(400, "{\"code\":-1121,\"msg\":\"Invalid symbol.\"}");
+
+        let config = Config::default().set_transport(transport);
+        let market: binance::market::Market = Binance::new_with_config(None, None, &config);
+
+        let err = market.get_price("XXXYYY").unwrap_err();
+        let response = err.binance_error().unwrap();
+        assert_eq!(response.status, 400);
+        assert_eq!(response.error_code(), BinanceErrorCode::BadSymbol);
+        assert_eq!(response.endpoint, "/api/v3/ticker/price");
+    }
+
+    #[test]
+    fn connection_errors_are_retried() {
+        #[derive(Debug)]
+        struct FlakyTransport {
+            inner: InMemoryTransport,
+            failures: Mutex<u32>,
+        }
+
+        impl Transport for FlakyTransport {
+            fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+                let mut failures = self.failures.lock().unwrap();
+                if *failures > 0 {
+                    *failures -= 1;
+                    let refused = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+                    return Err(refused.into());
+                }
+                self.inner.send(request)
+            }
+        }
+
+        let inner = InMemoryTransport::default();
+        inner.respond(200, "{}");
+        let config = Config::default()
+            .set_retry_policy(
+                binance::retry::RetryPolicy::new(3)
+                    .set_backoff(std::time::Duration::ZERO, std::time::Duration::ZERO),
+            )
+            .set_transport(FlakyTransport {
+                inner: inner.clone(),
+                failures: Mutex::new(2),
+            });
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        general.ping().unwrap();
+        assert_eq!(inner.requests.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,289 @@
+mod common;
+
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::errors::*;
+use binance::futures::account::CustomOrderRequest;
+use binance::futures::account::OrderType as FuturesOrderType;
+use binance::model::*;
+use binance::validator::*;
+use common::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+
+    fn spot_filters() -> Vec<Filters> {
+        vec![
+            Filters::PriceFilter {
+                min_price: "0.01000000".into(),
+                max_price: "1000000.00000000".into(),
+                tick_size: "0.01000000".into(),
+            },
+            Filters::LotSize {
+                min_qty: "0.00010000".into(),
+                max_qty: "9000.00000000".into(),
+                step_size: "0.00010000".into(),
+            },
+            Filters::MarketLotSize {
+                min_qty: "0.00000000".into(),
+                max_qty: "100.00000000".into(),
+                step_size: "0.00000000".into(),
+            },
+            Filters::Notional {
+                notional: None,
+                min_notional: Some("5.00000000".into()),
+                apply_to_market: None,
+                apply_min_to_market: Some(true),
+                max_notional: Some("9000000.00000000".into()),
+                apply_max_to_market: Some(false),
+                avg_price_mins: Some(5.0),
+            },
+            Filters::PercentPriceBySide {
+                bid_multiplier_up: "5".into(),
+                bid_multiplier_down: "0.2".into(),
+                ask_multiplier_up: "5".into(),
+                ask_multiplier_down: "0.2".into(),
+                avg_price_mins: Some(5.0),
+            },
+        ]
+    }
+
+    fn violation(error: Error) -> FilterViolation {
+        match error.kind() {
+            ErrorKind::FilterViolation(symbol, violation) => {
+                assert_eq!(symbol, "BTCUSDT");
+                violation.clone()
+            }
+            _ => panic!("{}", error),
+        }
+    }
+
+    #[test]
+    fn rounding() {
+        let validator = OrderValidator::new("BTCUSDT", &spot_filters()).unwrap();
+
+        assert_number(validator.round_price(number("60000.126")), "60000.13");
+        assert_eq!(validator.round_price(number("0.3")), number("0.3"));
+        assert_eq!(
+            validator.round_quantity(number("0.123456")),
+            number("0.1234")
+        );
+        assert_eq!(validator.round_quantity(number("0.3")), number("0.3"));
+        assert_eq!(
+            validator.round_market_quantity(number("0.123456")),
+            number("0.1234")
+        );
+    }
+
+    #[test]
+    fn violations() {
+        let validator = OrderValidator::new("BTCUSDT", &spot_filters()).unwrap();
+
+        assert!(
+            validator
+                .validate(
+                    &OrderSide::Buy,
+                    Some(number("60000.0")),
+                    number("0.001"),
+                    Some(number("60000.0"))
+                )
+                .is_ok()
+        );
+        assert_eq!(
+            violation(
+                validator
+                    .validate(
+                        &OrderSide::Buy,
+                        Some(number("60000.001")),
+                        number("0.001"),
+                        None
+                    )
+                    .unwrap_err()
+            ),
+            FilterViolation::PriceNotOnTick {
+                price: number("60000.001"),
+                tick_size: number("0.01")
+            }
+        );
+        assert_eq!(
+            violation(
+                validator
+                    .validate(
+                        &OrderSide::Buy,
+                        Some(number("60000.0")),
+                        number("0.00005"),
+                        None
+                    )
+                    .unwrap_err()
+            ),
+            FilterViolation::QuantityBelowMin {
+                quantity: number("0.00005"),
+                min_qty: number("0.0001")
+            }
+        );
+        assert!(matches!(
+            violation(
+                validator
+                    .validate(
+                        &OrderSide::Buy,
+                        Some(number("100.0")),
+                        number("0.001"),
+                        None
+                    )
+                    .unwrap_err()
+            ),
+            FilterViolation::NotionalBelowMin { .. }
+        ));
+        assert!(matches!(
+            violation(
+                validator
+                    .validate(
+                        &OrderSide::Sell,
+                        Some(number("6000.0")),
+                        number("0.01"),
+                        Some(number("60000.0"))
+                    )
+                    .unwrap_err()
+            ),
+            FilterViolation::PriceOutsidePercentBounds { .. }
+        ));
+        // MARKET_LOT_SIZE only applies to market orders
+        assert!(
+            validator
+                .validate(
+                    &OrderSide::Buy,
+                    Some(number("100.0")),
+                    number("200.0"),
+                    None
+                )
+                .is_ok()
+        );
+        assert!(matches!(
+            violation(
+                validator
+                    .validate(
+                        &OrderSide::Buy,
+                        None,
+                        number("200.0"),
+                        Some(number("60000.0"))
+                    )
+                    .unwrap_err()
+            ),
+            FilterViolation::QuantityAboveMax { .. }
+        ));
+    }
+
+    #[test]
+    fn spot_order_request() {
+        let validator = OrderValidator::new("BTCUSDT", &spot_filters()).unwrap();
+
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .set_quantity(number("0.123456"))
+            .set_price(number("60000.126"))
+            .set_time_in_force(TimeInForce::GTC)
+            .apply_filters(&validator, None)
+            .unwrap();
+        assert_eq!(order.quantity.unwrap().to_string(), "0.1234");
+        assert_eq!(order.price.unwrap().to_string(), "60000.13");
+
+        let order = SpotOrderRequest::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+            .set_quote_order_qty(1)
+            .apply_filters(&validator, None);
+        assert!(matches!(
+            violation(order.err().unwrap()),
+            FilterViolation::NotionalBelowMin { .. }
+        ));
+    }
+
+    #[test]
+    fn futures_order_request() {
+        let filters = vec![
+            Filters::PriceFilter {
+                min_price: "556.80".into(),
+                max_price: "4529764".into(),
+                tick_size: "0.10".into(),
+            },
+            Filters::LotSize {
+                min_qty: "0.001".into(),
+                max_qty: "1000".into(),
+                step_size: "0.001".into(),
+            },
+            Filters::MinNotional {
+                notional: Some("100".into()),
+                min_notional: None,
+                apply_to_market: None,
+                avg_price_mins: None,
+            },
+        ];
+        let validator = OrderValidator::new("BTCUSDT", &filters).unwrap();
+
+        let order = CustomOrderRequest {
+            algo_type: binance::futures::account::AlgoType::Conditional,
+            client_algo_id: None,
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Sell,
+            position_side: None,
+            order_type: FuturesOrderType::Limit,
+            time_in_force: None,
+            qty: Some(number("0.0025")),
+            reduce_only: None,
+            price: Some(number("60000.06")),
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+            new_client_order_id: None,
+            good_till_date: None,
+        };
+        let order = order.apply_filters(&validator, None).unwrap();
+        assert_eq!(order.qty.unwrap().to_string(), "0.002");
+        assert_eq!(order.price.unwrap().to_string(), "60000.1");
+
+        let error = CustomOrderRequest {
+            qty: Some(number("0.001")),
+            price: Some(number("1000.0")),
+            ..order
+        }
+        .apply_filters(&validator, None)
+        .err()
+        .unwrap();
+        assert_eq!(
+            violation(error),
+            FilterViolation::NotionalBelowMin {
+                notional: number("1.0"),
+                min_notional: number("100.0")
+            }
+        );
+    }
+
+    #[test]
+    fn account_order_validator() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::UrlEncoded("symbol".into(), "LTCBTC".into()))
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let validator = account.order_validator("ltcbtc").unwrap();
+        mock_exchange_info.assert();
+
+        assert_eq!(validator.symbol(), "LTCBTC");
+        assert_eq!(
+            validator.round_quantity(number("1.234")).to_string(),
+            "1.23"
+        );
+        assert_eq!(
+            validator.round_price(number("0.0123456")).to_string(),
+            "0.012346"
+        );
+    }
+}